@@ -0,0 +1,92 @@
+//! Generate a regression test for each part of each puzzle example, i.e. for every
+//! `dayNN/example*.txt` that is accompanied by a `dayNN/example*.expected` sidecar.
+//!
+//! Each line of a sidecar holds `<part> <answer>`; see `src/sidecar.rs`. Examples without a
+//! sidecar still get a test, which is ignored until one is added.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[path = "src/sidecar.rs"]
+mod sidecar;
+
+fn main() {
+    let root = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let mut days: Vec<_> = fs::read_dir(&root)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            let day = name.strip_prefix("day")?.parse::<u8>().ok()?;
+            Some((day, path))
+        })
+        .collect();
+    days.sort();
+
+    let mut tests = String::new();
+    for (day, dir) in days {
+        // Pick up examples that are added or removed.
+        println!("cargo:rerun-if-changed={}", dir.display());
+        let mut examples: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                let name = path.file_name().and_then(|name| name.to_str());
+                name.is_some_and(|name| name.starts_with("example") && name.ends_with(".txt"))
+            })
+            .collect();
+        examples.sort();
+
+        for example in examples {
+            let stem = example.file_stem().unwrap().to_str().unwrap();
+            let input = format!("day{:02}/{}.txt", day, stem);
+            let sidecar = example.with_extension("expected");
+            println!("cargo:rerun-if-changed={}", sidecar.display());
+            if !sidecar.exists() {
+                writeln!(
+                    tests,
+                    "#[test]\n#[ignore = \"no expected answers in {}\"]\nfn day{:02}_{}() {{}}\n",
+                    sidecar.strip_prefix(&root).unwrap().display(),
+                    day,
+                    stem,
+                )
+                .unwrap();
+                continue;
+            }
+            for (part, answer) in read_sidecar(&sidecar) {
+                writeln!(
+                    tests,
+                    "#[test]\nfn day{:02}_{}_part{}() {{\n    check_example({}, {:?}, Part::{}, {:?});\n}}\n",
+                    day,
+                    stem,
+                    part,
+                    day,
+                    input,
+                    if part == 1 { "One" } else { "Two" },
+                    answer,
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
+
+/// Read the `(part, answer)` pairs in the given sidecar, panicking on malformed lines.
+fn read_sidecar(path: &Path) -> Vec<(u8, String)> {
+    let text = fs::read_to_string(path).unwrap();
+    let mut answers = vec![];
+    for (i, line) in text.lines().enumerate() {
+        match sidecar::parse_line(line) {
+            Ok(None) => (),
+            Ok(Some(answer)) => answers.push(answer),
+            Err(reason) => panic!("{}:{}: {}; got {:?}", path.display(), i + 1, reason, line),
+        }
+    }
+    answers
+}
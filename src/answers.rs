@@ -0,0 +1,189 @@
+//! The expected answers of the puzzles, as checked by `aoc verify`: those to the inputs in
+//! `answers.txt`, and those to the examples in the `.expected` sidecar of each example.
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::sidecar;
+use crate::{read_parsed_with, Malformed, Part, Result};
+
+/// The expected answer to one part of a day's puzzle, for a specific input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+/// The whitespace-separated fields of `line`, each along with its byte offset within the line.
+fn fields(line: &str) -> impl Iterator<Item = (&str, usize)> {
+    let mut chars = line.char_indices();
+    std::iter::from_fn(move || {
+        let (start, _) = chars.find(|(_, c)| !c.is_whitespace())?;
+        let end = chars
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(line.len(), |(end, _)| end);
+        Some((&line[start..end], start))
+    })
+}
+
+impl FromStr for Expected {
+    type Err = Malformed;
+
+    /// Parse an entry of the form `<day> <part> <input> <answer>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = fields(s);
+        let mut next_field = |name| {
+            fields
+                .next()
+                .ok_or_else(|| Malformed::new(format!("missing <{}>", name)).at(s.len()))
+        };
+        let (day, day_offset) = next_field("day")?;
+        let (part, part_offset) = next_field("part")?;
+        let (input, _) = next_field("input")?;
+        let (answer, _) = next_field("answer")?;
+        Ok(Expected {
+            day: match day.parse() {
+                Ok(day @ 1..=25) => day,
+                _ => return Err(Malformed::new("expected a day in 1..=25").at(day_offset)),
+            },
+            part: part
+                .parse()
+                .map_err(|err: anyhow::Error| Malformed::new(err).at(part_offset))?,
+            input: PathBuf::from(input),
+            answer: answer.to_owned(),
+        })
+    }
+}
+
+/// Read the expected answers in the given `Path`, one per line; blank lines and lines starting
+/// with `#` are ignored.
+pub fn read_answers<P>(path: P) -> Result<Vec<Expected>>
+where
+    P: AsRef<Path>,
+{
+    Ok(read_parsed_with(path, |line| {
+        let entry = line.trim();
+        if entry.is_empty() || entry.starts_with('#') {
+            return Ok(None);
+        }
+        // NOTE: The offsets of the fields are those within the untrimmed line.
+        line.parse().map(Some)
+    })?
+    .into_iter()
+    .flatten()
+    .collect())
+}
+
+/// An example of a day's puzzle, i.e. a `dayNN/example*.txt` along with the expected answers in
+/// its `.expected` sidecar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub day: u8,
+    pub input: PathBuf,
+    pub answers: Vec<(Part, String)>,
+}
+
+/// Read the expected answers in the sidecar in the given `Path`, in the format that
+/// `src/sidecar.rs` describes.
+pub fn read_sidecar<P: AsRef<Path>>(path: P) -> Result<Vec<(Part, String)>> {
+    let answers = read_parsed_with(path, |line| {
+        sidecar::parse_line(line).map_err(Malformed::new)
+    })?;
+    Ok(answers
+        .into_iter()
+        .flatten()
+        .map(|(part, answer)| (Part::ALL[part as usize - 1], answer))
+        .collect())
+}
+
+/// Whether the given `Path` is an example or a sidecar, going by its name.
+pub fn is_example(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("");
+    name.starts_with("example") && (name.ends_with(".txt") || name.ends_with(".expected"))
+}
+
+/// The examples of `day` in the given directory that have a sidecar, in order of their names.
+pub fn examples<P: AsRef<Path>>(day: u8, dir: P) -> Result<Vec<Example>> {
+    let mut inputs: Vec<_> = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    inputs.retain(|path| is_example(path) && path.extension().is_some_and(|ext| ext == "txt"));
+    inputs.sort();
+    let mut examples = vec![];
+    for input in inputs {
+        let sidecar = input.with_extension("expected");
+        if !sidecar.exists() {
+            continue;
+        }
+        let answers = read_sidecar(&sidecar)?;
+        examples.push(Example {
+            day,
+            input,
+            answers,
+        });
+    }
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+    use crate::Error;
+
+    #[test]
+    fn parse_expected() {
+        assert_eq!(
+            "7 2 day07/example.txt 32".parse(),
+            Ok(Expected {
+                day: 7,
+                part: Part::Two,
+                input: PathBuf::from("day07/example.txt"),
+                answer: "32".to_owned(),
+            })
+        );
+        assert_eq!("26 1 x 1".parse::<Expected>().unwrap_err().offset, 0);
+        assert_eq!("7 3 x 1".parse::<Expected>().unwrap_err().offset, 2);
+        assert_eq!(" 7\u{a0}3 x 1".parse::<Expected>().unwrap_err().offset, 4);
+        assert!("7 1 day07/example.txt".parse::<Expected>().is_err());
+    }
+
+    #[test]
+    fn read_examples() -> Result<()> {
+        let examples = examples(10, "day10")?;
+        assert_eq!(examples.len(), 2);
+        let example = &examples[1];
+        assert_eq!(example.input, Path::new("day10/example0.txt"));
+        assert_eq!(
+            example.answers,
+            [(Part::One, "35".to_owned()), (Part::Two, "8".to_owned())]
+        );
+        assert!(is_example(Path::new("day07/example2.expected")));
+        assert!(!is_example(Path::new("day07/input.txt")));
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_sidecar() {
+        let dir = TempDir::new("sidecar");
+        let path = dir.join("example.expected");
+        std::fs::write(&path, "# comment\n1 127\n3 62\n").unwrap();
+        let err = read_sidecar(&path).unwrap_err();
+        assert!(matches!(err, Error::Parse { line: 3, .. }), "{:?}", err);
+    }
+
+    #[test]
+    fn registry() -> Result<()> {
+        let answers = read_answers("answers.txt")?;
+        assert!(answers.iter().any(|e| e.day == 1 && e.part == Part::One));
+        assert!(answers.iter().all(|e| e.input.exists()));
+        // The answers to the examples are in their sidecars only.
+        assert!(answers.iter().all(|e| !is_example(&e.input)));
+        Ok(())
+    }
+}
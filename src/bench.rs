@@ -0,0 +1,185 @@
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Part, Result};
+
+/// A separately timed stage of solving a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Stage {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Stage::Part1,
+            Part::Two => Stage::Part2,
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part 1"),
+            Stage::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Summary statistics of the timings of a single stage of a day's puzzle, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Measurement {
+    /// Summarize the given (non-empty) `samples` of `stage` of `day`.
+    pub fn new(day: u8, stage: Stage, samples: &[Duration]) -> Self {
+        let mut samples: Vec<_> = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as u64)
+            .collect();
+        samples.sort_unstable();
+        Measurement {
+            day,
+            stage,
+            iterations: samples.len(),
+            min_ns: samples[0],
+            median_ns: percentile(&samples, 50),
+            p95_ns: percentile(&samples, 95),
+        }
+    }
+}
+
+/// The `p`-th percentile of the given sorted, non-empty `samples`, using the nearest-rank method.
+fn percentile(samples: &[u64], p: usize) -> u64 {
+    let rank = (p * samples.len()).div_ceil(100).max(1);
+    samples[rank - 1]
+}
+
+/// A stage whose median time grew beyond the allowed threshold, compared to the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline_ns: u64,
+    pub median_ns: u64,
+}
+
+impl Regression {
+    /// The relative change of the median time, e.g. `0.25` for 25% slower.
+    pub fn change(&self) -> f64 {
+        self.median_ns as f64 / self.baseline_ns.max(1) as f64 - 1.0
+    }
+}
+
+/// Find the stages in `current` whose median time is slower than that of the same stage in
+/// `baseline` by more than `threshold` (e.g. `0.1` for 10%). Stages that are missing from the
+/// baseline are not regressions.
+pub fn regressions(
+    baseline: &[Measurement],
+    current: &[Measurement],
+    threshold: f64,
+) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|m| {
+            let base = baseline
+                .iter()
+                .find(|b| b.day == m.day && b.stage == m.stage)?;
+            let regression = Regression {
+                day: m.day,
+                stage: m.stage,
+                baseline_ns: base.median_ns,
+                median_ns: m.median_ns,
+            };
+            (regression.change() > threshold).then_some(regression)
+        })
+        .collect()
+}
+
+/// Read the benchmark results previously written with [`write_measurements`].
+pub fn read_measurements<P: AsRef<Path>>(path: P) -> Result<Vec<Measurement>> {
+    let path = path.as_ref();
+    serde_json::from_reader(BufReader::new(File::open(path)?))
+        .map_err(|err| Error::InvalidInput(format!("{}: {}", path.display(), err)))
+}
+
+/// Write the given benchmark results in the given `Path`, as JSON.
+pub fn write_measurements<P: AsRef<Path>>(path: P, measurements: &[Measurement]) -> Result<()> {
+    serde_json::to_writer_pretty(BufWriter::new(File::create(path)?), measurements)
+        .map_err(|err| Error::Io(err.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn summarize() {
+        let m = Measurement::new(1, Stage::Parse, &millis(&[5, 1, 4, 2, 3]));
+        assert_eq!(
+            (m.min_ns, m.median_ns, m.p95_ns),
+            (1_000_000, 3_000_000, 5_000_000)
+        );
+        assert_eq!(m.iterations, 5);
+
+        let samples: Vec<_> = (1..=100).rev().collect();
+        let m = Measurement::new(1, Stage::Part1, &millis(&samples));
+        assert_eq!((m.median_ns, m.p95_ns), (50_000_000, 95_000_000));
+
+        let m = Measurement::new(1, Stage::Part2, &millis(&[7]));
+        assert_eq!(
+            (m.min_ns, m.median_ns, m.p95_ns),
+            (7_000_000, 7_000_000, 7_000_000)
+        );
+    }
+
+    #[test]
+    fn find_regressions() {
+        let baseline = vec![
+            Measurement::new(1, Stage::Parse, &millis(&[10])),
+            Measurement::new(1, Stage::Part1, &millis(&[10])),
+        ];
+        let current = vec![
+            Measurement::new(1, Stage::Parse, &millis(&[11])),
+            Measurement::new(1, Stage::Part1, &millis(&[12])),
+            Measurement::new(1, Stage::Part2, &millis(&[100])),
+        ];
+        let found = regressions(&baseline, &current, 0.15);
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].day, found[0].stage), (1, Stage::Part1));
+        assert!((found[0].change() - 0.2).abs() < 1e-9);
+        assert_eq!(regressions(&baseline, &current, 0.05).len(), 2);
+    }
+
+    #[test]
+    fn json_roundtrip() -> Result<()> {
+        let dir = TempDir::new("bench");
+        let path = dir.join("bench.json");
+        let measurements = vec![Measurement::new(3, Stage::Part2, &millis(&[1, 2]))];
+        write_measurements(&path, &measurements)?;
+        assert_eq!(read_measurements(&path)?, measurements);
+        Ok(())
+    }
+}
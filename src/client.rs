@@ -0,0 +1,319 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use regex::Regex;
+
+use crate::{Error, Part, Result};
+
+/// The server that hosts the puzzles, unless another one is configured.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The year of the event that this repository solves.
+const YEAR: u16 = 2020;
+
+/// A client for the puzzle server, authenticated with a session token.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    /// Create a client for the server at `base_url` (e.g. [`BASE_URL`]), which authenticates
+    /// with the given `session` cookie.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent("github.com/ckatsak/aoc2020")
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+        }
+    }
+
+    /// The URL of the given `path` below the page of `day`'s puzzle.
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, YEAR, day, path)
+    }
+
+    /// Send the given request with the session cookie, returning the body of the response.
+    fn send(&self, request: ureq::Request, form: Option<&[(&str, &str)]>) -> Result<String> {
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        }
+        .map_err(|err| match err {
+            ureq::Error::Status(status, response) => Error::Http(format!(
+                "{} {}: {}",
+                status,
+                response.status_text().to_owned(),
+                response.into_string().unwrap_or_default().trim()
+            )),
+            err => Error::Http(err.to_string()),
+        })?;
+        Ok(response.into_string()?)
+    }
+
+    /// Download the puzzle input of the given `day`.
+    pub fn input(&self, day: u8) -> Result<String> {
+        self.send(self.agent.get(&self.url(day, "/input")), None)
+    }
+
+    /// Submit the `answer` to the given `part` of `day`'s puzzle, and find out how it went.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Outcome> {
+        let level = part.to_string();
+        let page = self.send(
+            self.agent.post(&self.url(day, "/answer")),
+            Some(&[("level", &level), ("answer", answer)]),
+        )?;
+        Outcome::from_page(&page)
+            .ok_or_else(|| Error::Http("unrecognized response to the submission".to_owned()))
+    }
+
+    /// Download the puzzle input of the given `day` into `path`, unless it exists already.
+    ///
+    /// Returns whether the input was downloaded. The file is written only once the whole input has
+    /// been received, so an interrupted download never leaves a partial input behind.
+    pub fn fetch_input<P: AsRef<Path>>(&self, day: u8, path: P) -> Result<bool> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Ok(false);
+        }
+        let input = self.input(day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = path.with_extension("part");
+        fs::write(&tmp, input)?;
+        fs::rename(tmp, path)?;
+        Ok(true)
+    }
+}
+
+/// The verdict on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// The answer is wrong, and too high.
+    TooHigh,
+    /// The answer is wrong, and too low.
+    TooLow,
+    /// The answer is wrong, with no hint as to why.
+    Incorrect,
+    /// The answer was not checked, since the previous one was submitted too recently; another
+    /// one may be submitted after the given wait.
+    RateLimited(Duration),
+    /// The answer was not checked, since the part has already been solved (or is still locked).
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Make sense of the page that the server responds to a submission with.
+    pub fn from_page(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if page.contains("That's not the right answer") {
+            Some(if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Incorrect
+            })
+        } else if page.contains("You gave an answer too recently") {
+            let re = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let wait = re.captures(page).map_or(0, |caps| {
+                let minutes = caps.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+                minutes * 60 + caps[2].parse::<u64>().unwrap_or(0)
+            });
+            Some(Outcome::RateLimited(Duration::from_secs(wait)))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Outcome::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the outcome is a verdict on the answer itself, as opposed to a refusal to check it.
+    pub fn is_verdict(&self) -> bool {
+        !matches!(self, Outcome::RateLimited(_) | Outcome::AlreadySolved)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::RateLimited(wait) => write!(f, "rate-limited ({}s left)", wait.as_secs()),
+            Outcome::AlreadySolved => write!(f, "already-solved"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    /// Parse one of the verdicts, as they are displayed.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            "incorrect" => Ok(Outcome::Incorrect),
+            _ => Err(format!("unknown outcome {:?}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    use super::*;
+    use crate::testing::TempDir;
+
+    /// A request received by the [`serve`] stand-in server.
+    #[derive(Debug)]
+    pub(crate) struct Request {
+        pub(crate) line: String,
+        pub(crate) headers: Vec<String>,
+        pub(crate) body: String,
+    }
+
+    impl Request {
+        pub(crate) fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter().find_map(|header| {
+                let (key, value) = header.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then(|| value.trim())
+            })
+        }
+    }
+
+    /// Start a local HTTP server that answers each request in turn with the next of the given
+    /// `(status, body)` responses, returning its base URL and a channel of the received requests.
+    pub(crate) fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut headers = vec![];
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    headers.push(header.trim().to_owned());
+                }
+                let mut request = Request {
+                    line: line.trim().to_owned(),
+                    headers,
+                    body: String::new(),
+                };
+                let length = request
+                    .header("Content-Length")
+                    .map_or(0, |len| len.parse().unwrap());
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.body = String::from_utf8(content).unwrap();
+                let _ = tx.send(request);
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (url, rx)
+    }
+
+    #[test]
+    fn download_input() -> Result<()> {
+        let (url, requests) = serve(vec![(200, "1\n2\n3\n")]);
+        let client = Client::new(&url, "cafe\n");
+        assert_eq!(client.input(7)?, "1\n2\n3\n");
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "GET /2020/day/7/input HTTP/1.1");
+        assert_eq!(request.header("Cookie"), Some("session=cafe"));
+        Ok(())
+    }
+
+    #[test]
+    fn download_error() {
+        let (url, _requests) = serve(vec![(400, "Please log in.")]);
+        match Client::new(&url, "stale").input(1) {
+            Err(Error::Http(reason)) => assert!(reason.contains("400"), "{}", reason),
+            res => panic!("unexpected result {:?}", res),
+        }
+    }
+
+    #[test]
+    fn submit_answer() -> Result<()> {
+        let (url, requests) = serve(vec![
+            (200, "<article><p>That's the right answer!  You are one gold star closer.</p></article>"),
+            (200, "<p>That's not the right answer; your answer is too low.  If you're stuck...</p>"),
+            (200, "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait.</p>"),
+        ]);
+        let client = Client::new(&url, "cafe");
+        assert_eq!(client.submit(3, Part::Two, "336")?, Outcome::Correct);
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "POST /2020/day/3/answer HTTP/1.1");
+        assert_eq!(request.header("Cookie"), Some("session=cafe"));
+        assert_eq!(request.body, "level=2&answer=336");
+        assert_eq!(client.submit(3, Part::One, "1")?, Outcome::TooLow);
+        assert_eq!(
+            client.submit(3, Part::One, "2")?,
+            Outcome::RateLimited(Duration::from_secs(252))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn outcome_from_page() {
+        let wrong = "That's not the right answer; your answer is too high.";
+        assert_eq!(Outcome::from_page(wrong), Some(Outcome::TooHigh));
+        let wrong = "That's not the right answer.  If you're stuck, ...";
+        assert_eq!(Outcome::from_page(wrong), Some(Outcome::Incorrect));
+        let limited = "You gave an answer too recently. You have 35s left to wait.";
+        assert_eq!(
+            Outcome::from_page(limited),
+            Some(Outcome::RateLimited(Duration::from_secs(35)))
+        );
+        let solved = "You don't seem to be solving the right level.  Did you already complete it?";
+        assert_eq!(Outcome::from_page(solved), Some(Outcome::AlreadySolved));
+        assert_eq!(Outcome::from_page("<html>Gateway timeout</html>"), None);
+    }
+
+    #[test]
+    fn fetch_is_cached() -> Result<()> {
+        let dir = TempDir::new("fetch");
+        let path = dir.join("day05/input.txt");
+        // The server answers a single request; a second download would fail to connect.
+        let (url, requests) = serve(vec![(200, "FBFBBFFRLR\n")]);
+        let client = Client::new(&url, "cafe");
+        assert!(client.fetch_input(5, &path)?);
+        assert!(!client.fetch_input(5, &path)?);
+        assert_eq!(fs::read_to_string(&path)?, "FBFBBFFRLR\n");
+        assert_eq!(requests.iter().count(), 1);
+        Ok(())
+    }
+}
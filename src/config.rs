@@ -0,0 +1,175 @@
+//! The project configuration, which overrides the input and the parameters of each day, e.g.:
+//!
+//! ```toml
+//! [day01]
+//! input = "inputs/expenses.txt"
+//! target = 2021
+//!
+//! [day07]
+//! bag = "light red"
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::{Error, Puzzle, Result};
+
+/// The configuration file, relative to the root of the repository.
+pub const CONFIG: &str = "aoc.toml";
+
+/// The overrides of a single day.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DayConfig {
+    /// The input to read instead of the day's own.
+    pub input: Option<String>,
+    /// The values of the day's parameters; see [`Puzzle::params`].
+    pub params: Map<String, Value>,
+}
+
+/// The overrides of each day, e.g. as read from [`CONFIG`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    days: BTreeMap<u8, DayConfig>,
+}
+
+impl Config {
+    /// Read the configuration in the given `Path`.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        Self::parse(&fs::read_to_string(path)?, &path.display().to_string())
+    }
+
+    /// Parse the configuration in `text`, with a `[dayNN]` table per day; `input` names it in
+    /// errors.
+    pub fn parse(text: &str, input: &str) -> Result<Self> {
+        let invalid = |reason: String| Error::InvalidInput(format!("{}: {}", input, reason));
+        let tables: toml::Table = toml::from_str(text).map_err(|err| invalid(err.to_string()))?;
+        let mut config = Config::default();
+        for (name, table) in tables {
+            let day = name
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| invalid(format!("expected `[dayNN]` tables; got {:?}", name)))?;
+            let table = match table {
+                toml::Value::Table(table) => table,
+                _ => return Err(invalid(format!("expected `[{}]` to be a table", name))),
+            };
+            let day_config = config.day_mut(day);
+            for (key, value) in table {
+                match (key.as_str(), value) {
+                    ("input", toml::Value::String(path)) => day_config.input = Some(path),
+                    ("input", _) => {
+                        return Err(invalid(format!("expected `{}.input` to be a path", name)))
+                    }
+                    (_, value) => {
+                        let value = serde_json::to_value(value)
+                            .map_err(|err| invalid(format!("{}.{}: {}", name, key, err)))?;
+                        day_config.params.insert(key, value);
+                    }
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// The overrides of the given `day`, if any.
+    pub fn day(&self, day: u8) -> Option<&DayConfig> {
+        self.days.get(&day)
+    }
+
+    /// The overrides of the given `day`, to be amended (e.g., by command-line flags).
+    pub fn day_mut(&mut self, day: u8) -> &mut DayConfig {
+        self.days.entry(day).or_default()
+    }
+
+    /// The input of `puzzle`: the overridden one, if any, or else the day's own.
+    pub fn input<'a>(&'a self, puzzle: &dyn Puzzle) -> &'a str {
+        self.day(puzzle.day())
+            .and_then(|day| day.input.as_deref())
+            .unwrap_or_else(|| puzzle.default_input())
+    }
+
+    /// The `puzzle` with its parameters overridden, if any.
+    ///
+    /// Configured puzzles are leaked, since each is only created once for the whole process.
+    pub fn configure(&self, puzzle: &'static dyn Puzzle) -> Result<&'static dyn Puzzle> {
+        match self.day(puzzle.day()) {
+            Some(day) if !day.params.is_empty() => Ok(Box::leak(puzzle.with_params(&day.params)?)),
+            _ => Ok(puzzle),
+        }
+    }
+}
+
+/// Parse the override of a single parameter given as `<name>=<value>` (e.g., on the command line),
+/// where the value is read as TOML, or else taken as a string; e.g., `target=2021` is a number, while
+/// `bag=light red` is a string.
+pub fn parse_param(s: &str) -> Result<(String, Value)> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| Error::InvalidInput(format!("expected `<name>=<value>`; got {:?}", s)))?;
+    let value = match toml::from_str::<toml::Table>(&format!("value = {}", value)) {
+        Ok(mut table) => serde_json::to_value(table.remove("value"))
+            .map_err(|err| Error::InvalidInput(format!("{}: {}", name, err)))?,
+        Err(_) => Value::String(value.to_owned()),
+    };
+    Ok((name.trim().to_owned(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day01, day02, day07};
+
+    #[test]
+    fn parse_config() -> Result<()> {
+        let config = Config::parse(
+            "[day01]\ninput = \"expenses.txt\"\ntarget = 2021\n\n[day07]\nbag = \"light red\"\n",
+            "aoc.toml",
+        )?;
+        assert_eq!(config.input(&day01::Day01::DEFAULT), "expenses.txt");
+        assert_eq!(config.input(&day07::Day07::DEFAULT), "day07/input.txt");
+        assert_eq!(
+            config.configure(&day01::Day01::DEFAULT)?.params()["target"],
+            2021
+        );
+        assert_eq!(
+            config.configure(&day07::Day07::DEFAULT)?.params()["bag"],
+            "light red"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_params() -> Result<()> {
+        assert_eq!(
+            parse_param("target=2021")?,
+            ("target".to_owned(), 2021.into())
+        );
+        assert_eq!(
+            parse_param("bag=\"light red\"")?,
+            ("bag".to_owned(), "light red".into())
+        );
+        assert_eq!(
+            parse_param("bag=light red")?,
+            ("bag".to_owned(), "light red".into())
+        );
+        assert!(parse_param("target").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn reject_bad_config() {
+        assert!(Config::parse("[dayone]\n", "aoc.toml").is_err());
+        assert!(Config::parse("[day01]\ninput = 1\n", "aoc.toml").is_err());
+
+        let config =
+            Config::parse("[day01]\ntarget = \"x\"\n[day02]\nx = 1\n", "aoc.toml").unwrap();
+        assert!(config.configure(&day01::Day01::DEFAULT).is_err());
+        assert!(config.configure(&day02::Day02).is_err());
+        let config = Config::parse("[day01]\ntrget = 2021\n", "aoc.toml").unwrap();
+        assert!(config.configure(&day01::Day01::DEFAULT).is_err());
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use crate::gen::check_size;
+use crate::{Generated, Part, Result, Rng};
+
+use super::TARGET;
+
+/// Generate `size` expenses, among which exactly one pair and exactly one triple add up to
+/// [`TARGET`].
+///
+/// The pair is a small expense (at most 300) and its large complement, while the triple consists
+/// of three distinct expenses in `301..=1009`. Every other expense lies in `1011..TARGET`, so it
+/// cannot take part in a pair with any expense but a small one, nor in a triple with any but two
+/// small ones; the complements of those few sums are never used.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 5)?;
+    let small = rng.between(1, 300);
+    let (c, d, e) = loop {
+        let (c, d) = (rng.between(301, 1009), rng.between(301, 1009));
+        let e = TARGET - c - d;
+        if (301..=1009).contains(&e) && c != d && d != e && c != e {
+            break (c, d, e);
+        }
+    };
+
+    let smalls = [small, c, d, e];
+    let mut forbidden: HashSet<_> = smalls.iter().map(|x| TARGET - x).collect();
+    for (i, x) in smalls.iter().enumerate() {
+        for y in &smalls[i + 1..] {
+            forbidden.insert(TARGET.saturating_sub(x + y));
+        }
+    }
+    let mut expenses = vec![small, TARGET - small, c, d, e];
+    while expenses.len() < size {
+        let filler = rng.between(1011, TARGET - 1);
+        if !forbidden.contains(&filler) {
+            expenses.push(filler);
+        }
+    }
+    rng.shuffle(&mut expenses);
+
+    let input: String = expenses.iter().map(|x| format!("{}\n", x)).collect();
+    Ok(Generated::new(input)
+        .with_answer(Part::One, small * (TARGET - small))
+        .with_answer(Part::Two, c * d * e))
+}
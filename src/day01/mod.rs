@@ -0,0 +1,90 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{details, read_parsed, Details, Explanation, Generated, Result, Rng, Solution};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+
+/// The sum that the expenses must add up to by default, which the generated inputs are built for.
+const TARGET: u64 = 2020;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day01 {
+    /// The sum that the expenses must add up to.
+    pub target: u64,
+}
+
+impl Day01 {
+    pub const DEFAULT: Day01 = Day01 { target: TARGET };
+}
+
+impl Solution for Day01 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day01/input.txt"
+    }
+
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        read_parsed(path)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, expenses: &Self::Input) -> Result<Self::Answer1> {
+        let mut expenses = expenses.clone();
+        let (i, j) = part1::two_sum(&mut expenses, self.target)?;
+        Ok(expenses[i] * expenses[j])
+    }
+
+    fn part2(&self, expenses: &Self::Input) -> Result<Self::Answer2> {
+        let (i, j, k) = part2::three_sum(expenses, self.target)?;
+        Ok(expenses[i] * expenses[j] * expenses[k])
+    }
+
+    /// The two expenses that add up to the target.
+    fn details1(&self, expenses: &Self::Input, _: &Self::Answer1) -> Result<Details> {
+        let mut expenses = expenses.clone();
+        let (i, j) = part1::two_sum(&mut expenses, self.target)?;
+        Ok(details(vec![("expenses", vec![expenses[i], expenses[j]])]))
+    }
+
+    /// The three expenses that add up to the target.
+    fn details2(&self, expenses: &Self::Input, _: &Self::Answer2) -> Result<Details> {
+        let (i, j, k) = part2::three_sum(expenses, self.target)?;
+        Ok(details(vec![(
+            "expenses",
+            vec![expenses[i], expenses[j], expenses[k]],
+        )]))
+    }
+
+    fn explain1(&self, expenses: &Self::Input, answer: &Self::Answer1) -> Result<Explanation> {
+        let mut expenses = expenses.clone();
+        let (i, j) = part1::two_sum(&mut expenses, self.target)?;
+        Ok(vec![
+            format!("{} + {} = {}", expenses[i], expenses[j], self.target),
+            format!("{} * {} = {}", expenses[i], expenses[j], answer),
+        ])
+    }
+
+    fn explain2(&self, expenses: &Self::Input, answer: &Self::Answer2) -> Result<Explanation> {
+        let (i, j, k) = part2::three_sum(expenses, self.target)?;
+        let (a, b, c) = (expenses[i], expenses[j], expenses[k]);
+        Ok(vec![
+            format!("{} + {} + {} = {}", a, b, c, self.target),
+            format!("{} * {} * {} = {}", a, b, c, answer),
+        ])
+    }
+}
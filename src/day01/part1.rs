@@ -0,0 +1,81 @@
+use crate::{Error, Result};
+
+/// Given a vector of `u64` integers and a `u64` target integer, this function calculates the 2SUM
+/// and returns the indices of the resulting integers in the vector (in their final position; i.e.,
+/// after sorting it).
+///
+/// An Error is returned if a solution for the 2SUM does not exist.
+pub fn two_sum(expenses: &mut [u64], target: u64) -> Result<(usize, usize)> {
+    expenses.sort_unstable();
+    // Only the expenses before `last` may take part in a solution; it shrinks as `curr` grows.
+    let mut last = expenses.partition_point(|&e| e <= target);
+    for (i, curr) in expenses.iter().enumerate() {
+        if last <= i + 1 {
+            break; // 2SUM solution does not exist
+        }
+        last = match &expenses[i + 1..last].binary_search(&(target - curr)) {
+            Ok(j) => {
+                return Ok((i, i + 1 + j)); // translate j due to subslicing
+            }
+            Err(j) => i + 1 + j,
+        }
+    }
+    Err(Error::NoSolution(format!(
+        "no two expenses add up to {}",
+        target
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+    use anyhow::{bail, Result};
+
+    /// Reference 2SUM: try every pair of distinct indices, in O(n^2).
+    fn two_sum_naive(expenses: &[u64], target: u64) -> Option<(usize, usize)> {
+        (0..expenses.len())
+            .flat_map(|i| (i + 1..expenses.len()).map(move |j| (i, j)))
+            .find(|&(i, j)| expenses[i] + expenses[j] == target)
+    }
+
+    #[test]
+    fn same_as_naive() {
+        let mut rng = Rng::new(1);
+        for _ in 0..2000 {
+            let len = rng.between(0, 12) as usize;
+            let mut v: Vec<_> = (0..len).map(|_| rng.between(0, 30)).collect();
+            let target = rng.between(0, 60);
+            let expected = two_sum_naive(&v, target);
+            match two_sum(&mut v, target) {
+                Ok((i, j)) => {
+                    assert!(expected.is_some(), "{:?} has no 2SUM to {}", v, target);
+                    assert!(i != j && v[i] + v[j] == target, "{:?}: ({}, {})", v, i, j);
+                }
+                Err(_) => assert_eq!(expected, None, "{:?} has a 2SUM to {}", v, target),
+            }
+        }
+    }
+
+    #[test]
+    fn t1() -> Result<()> {
+        let mut v = vec![10, 10, 10, 10, 10, 10, 10];
+        let (i, j) = two_sum(&mut v, 20)?;
+        assert_eq!(v[i] + v[j], 20);
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let mut v: Vec<_> = (1..10).collect();
+        match two_sum(&mut v, 20) {
+            Ok((i, j)) => {
+                bail!("This should return an Err! ({} + {})", v[i], v[j]);
+            }
+            Err(e) => {
+                eprintln!("error = {:#?}", e);
+                Ok(())
+            }
+        }
+    }
+}
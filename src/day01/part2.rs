@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use crate::{Error, Result};
+
+/// Given a vector of `u64` integers and a `u64` target integer, this function calculates a
+/// solution to the 3SUM problem and returns the indices of the resulting integers in the vector.
+///
+/// An Error is returned if a solution for the 3SUM does not exist.
+pub fn three_sum(expenses: &[u64], target: u64) -> Result<(usize, usize, usize)> {
+    // The last index of each expense, so that a third one is only looked for after the first two.
+    let mut h: HashMap<u64, usize> = HashMap::with_capacity(expenses.len());
+    expenses.iter().enumerate().for_each(|(i, e)| {
+        h.insert(*e, i);
+    });
+    for (i, ei) in expenses.iter().enumerate() {
+        for (j, ej) in expenses.iter().enumerate().skip(i + 1) {
+            let t_ei_ej = if let Some(t_ei) = target.checked_sub(*ei) {
+                if let Some(t_ei_ej) = t_ei.checked_sub(*ej) {
+                    t_ei_ej
+                } else {
+                    continue;
+                }
+            } else {
+                continue;
+            };
+            match h.get(&t_ei_ej) {
+                Some(&k) if k > j => return Ok((i, j, k)),
+                _ => (),
+            }
+        }
+    }
+    Err(Error::NoSolution(format!(
+        "no three expenses add up to {}",
+        target
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+    use anyhow::Result;
+
+    /// Reference 3SUM: try every triple of distinct indices, in O(n^3).
+    fn three_sum_naive(expenses: &[u64], target: u64) -> Option<(usize, usize, usize)> {
+        let n = expenses.len();
+        (0..n)
+            .flat_map(|i| (i + 1..n).flat_map(move |j| (j + 1..n).map(move |k| (i, j, k))))
+            .find(|&(i, j, k)| expenses[i] + expenses[j] + expenses[k] == target)
+    }
+
+    #[test]
+    fn same_as_naive() {
+        let mut rng = Rng::new(2);
+        for _ in 0..2000 {
+            let len = rng.between(0, 10) as usize;
+            let v: Vec<_> = (0..len).map(|_| rng.between(0, 30)).collect();
+            let target = rng.between(0, 90);
+            let expected = three_sum_naive(&v, target);
+            match three_sum(&v, target) {
+                Ok((i, j, k)) => {
+                    assert!(expected.is_some(), "{:?} has no 3SUM to {}", v, target);
+                    assert!(
+                        i != j && j != k && i != k && v[i] + v[j] + v[k] == target,
+                        "{:?}: ({}, {}, {})",
+                        v,
+                        i,
+                        j,
+                        k
+                    );
+                }
+                Err(_) => assert_eq!(expected, None, "{:?} has a 3SUM to {}", v, target),
+            }
+        }
+    }
+
+    #[test]
+    fn t1() -> Result<()> {
+        let v = vec![10, 10, 10, 10, 10, 10, 10];
+        let (i, j, k) = three_sum(&v, 30)?;
+        assert_eq!(v[i] + v[j] + v[k], 30);
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt::Write as _;
+
+use crate::gen::check_size;
+use crate::{Generated, Part, Result, Rng};
+
+/// Generate `size` entries, about half of which satisfy each policy.
+///
+/// Each password is at least `upper` letters long, so that both positions of the second policy
+/// lie within it.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 1)?;
+    let mut input = String::new();
+    let (mut valid1, mut valid2) = (0, 0);
+    for _ in 0..size {
+        let lower = rng.between(1, 10) as usize;
+        let upper = rng.between(lower as u64, lower as u64 + 10) as usize;
+        let letter = (b'a' + rng.between(0, 25) as u8) as char;
+        // Favour the letter of the policy, so that both valid and invalid passwords are common.
+        let bias = rng.between(20, 80) as f64 / 100.0;
+        let password: String = (0..rng.between(upper as u64, upper as u64 + 5))
+            .map(|_| {
+                if rng.chance(bias) {
+                    letter
+                } else {
+                    (b'a' + rng.between(0, 25) as u8) as char
+                }
+            })
+            .collect();
+
+        let count = password.matches(letter).count();
+        if (lower..=upper).contains(&count) {
+            valid1 += 1;
+        }
+        let letters: Vec<_> = password.chars().collect();
+        if (letters[lower - 1] == letter) != (letters[upper - 1] == letter) {
+            valid2 += 1;
+        }
+        writeln!(input, "{}-{} {}: {}", lower, upper, letter, password).unwrap();
+    }
+    Ok(Generated::new(input)
+        .with_answer(Part::One, valid1)
+        .with_answer(Part::Two, valid2))
+}
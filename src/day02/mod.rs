@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{details, read_parsed, Details, Generated, Malformed, Result, Rng, Solution};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+
+/// A password along with the corporate policy that was in effect when it was set.
+#[derive(Debug)]
+pub struct Entry {
+    lower: usize,
+    upper: usize,
+    letter: char,
+    password: String,
+}
+
+impl std::str::FromStr for Entry {
+    type Err = Malformed;
+
+    /// Parse an entry of the form `<lower>-<upper> <letter>: <password>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (policy, password) = s
+            .split_once(": ")
+            .ok_or_else(|| Malformed::new("expected `<policy>: <password>`").at(s.len()))?;
+        let (range, letter) = policy
+            .split_once(' ')
+            .ok_or_else(|| Malformed::new("expected `<range> <letter>`").at(policy.len()))?;
+        let (lower, upper) = range
+            .split_once('-')
+            .ok_or_else(|| Malformed::new("expected `<lower>-<upper>`"))?;
+        let mut letters = letter.chars();
+        let letter = match (letters.next(), letters.next()) {
+            (Some(letter), None) => letter,
+            _ => return Err(Malformed::new("expected a single letter").at(range.len() + 1)),
+        };
+        Ok(Entry {
+            lower: lower.parse()?,
+            upper: upper
+                .parse()
+                .map_err(|err| Malformed::from(err).at(lower.len() + 1))?,
+            letter,
+            password: password.to_owned(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day02/input.txt"
+    }
+
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        read_parsed(path)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, entries: &Self::Input) -> Result<Self::Answer1> {
+        Ok(entries
+            .iter()
+            .filter(|entry| part1::is_valid(entry))
+            .count())
+    }
+
+    fn part2(&self, entries: &Self::Input) -> Result<Self::Answer2> {
+        entries.iter().try_fold(0, |count, entry| {
+            Ok(if part2::is_valid(entry)? {
+                count + 1
+            } else {
+                count
+            })
+        })
+    }
+
+    fn details1(&self, entries: &Self::Input, _: &Self::Answer1) -> Result<Details> {
+        Ok(details(vec![("entries", entries.len())]))
+    }
+
+    fn details2(&self, entries: &Self::Input, _: &Self::Answer2) -> Result<Details> {
+        Ok(details(vec![("entries", entries.len())]))
+    }
+}
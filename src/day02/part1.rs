@@ -0,0 +1,10 @@
+use super::Entry;
+
+/// The letter must appear in the password at least `lower` and at most `upper` times.
+pub fn is_valid(entry: &Entry) -> bool {
+    let cnt = entry.password.matches(entry.letter).count();
+    cnt >= entry.lower && cnt <= entry.upper
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,26 @@
+use crate::{Error, Result};
+
+use super::Entry;
+
+/// Exactly one of the (1-indexed) positions `lower` and `upper` of the password must contain the
+/// letter.
+///
+/// An error is returned if either of the positions lies outside the password.
+pub fn is_valid(entry: &Entry) -> Result<bool> {
+    let letter_at = |position: usize| {
+        position
+            .checked_sub(1)
+            .and_then(|i| entry.password.chars().nth(i))
+            .map(|c| c == entry.letter)
+            .ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "position {} is out of the bounds of password {:?}",
+                    position, entry.password
+                ))
+            })
+    };
+    Ok(letter_at(entry.lower)? ^ letter_at(entry.upper)?)
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,43 @@
+use super::SLOPES;
+use crate::gen::check_size;
+use crate::{Generated, Part, Result, Rng};
+
+/// The width of the map, which repeats to the right; the same as that of the real inputs.
+const WIDTH: usize = 31;
+
+/// Generate a map of `size` rows, about a fifth of whose squares are trees; the top-left square is
+/// always open.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 1)?;
+    let map: Vec<Vec<u8>> = (0..size)
+        .map(|i| {
+            (0..WIDTH)
+                .map(|j| {
+                    if (i, j) != (0, 0) && rng.chance(0.2) {
+                        b'#'
+                    } else {
+                        b'.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let trees = |(right, down): (usize, usize)| {
+        (1..)
+            .map(|step| (step * down, step * right % WIDTH))
+            .take_while(|&(i, _)| i < size)
+            .filter(|&(i, j)| map[i][j] == b'#')
+            .count()
+    };
+    let input: String = map
+        .iter()
+        .map(|row| String::from_utf8_lossy(row) + "\n")
+        .collect();
+    Ok(Generated::new(input)
+        .with_answer(Part::One, trees((3, 1)))
+        .with_answer(
+            Part::Two,
+            SLOPES.iter().map(|&slope| trees(slope)).product::<usize>(),
+        ))
+}
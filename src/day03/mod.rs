@@ -0,0 +1,99 @@
+use std::convert::TryFrom;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{details, Details, Generated, Grid, Malformed, Result, Rng, Solution, Visualize};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+mod render;
+
+/// The slopes of the second part, as `(right, down)`.
+const SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+/// A square of the map, which repeats to the right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Square {
+    Open,
+    Tree,
+}
+
+impl TryFrom<char> for Square {
+    type Error = Malformed;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Square::Open),
+            '#' => Ok(Square::Tree),
+            _ => Err(Malformed::new("expected `.` or `#`")),
+        }
+    }
+}
+
+impl std::fmt::Display for Square {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Square::Open => write!(f, "."),
+            Square::Tree => write!(f, "#"),
+        }
+    }
+}
+
+/// Count the trees encountered on the `map` while following the slope of `right`, `down` from the
+/// top-left square to the bottom.
+pub fn trees_on_slope(map: &Grid<Square>, (right, down): (usize, usize)) -> usize {
+    (1..)
+        .map(|step| map.get_wrapping((step * down, step * right)))
+        .take_while(Option::is_some)
+        .filter(|&square| square == Some(&Square::Tree))
+        .count()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<Square>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day03/input.txt"
+    }
+
+    /// Read the map of open squares (`.`) and trees (`#`), one row per line.
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        Grid::read(path)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Answer1> {
+        Ok(part1::count_trees(map))
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Answer2> {
+        Ok(part2::count_trees(map).iter().product())
+    }
+
+    fn visualization(&self) -> Option<&dyn Visualize<Input = Self::Input>> {
+        Some(self)
+    }
+
+    fn details1(&self, _: &Self::Input, _: &Self::Answer1) -> Result<Details> {
+        Ok(details(vec![("right", 3), ("down", 1)]))
+    }
+
+    /// The trees encountered on each of the slopes, in the order they are listed in the puzzle.
+    fn details2(&self, map: &Self::Input, _: &Self::Answer2) -> Result<Details> {
+        Ok(details(vec![("trees", part2::count_trees(map))]))
+    }
+}
@@ -0,0 +1,9 @@
+use super::{trees_on_slope, Grid, Square};
+
+/// Count the trees encountered on the map while following a slope of right 3, down 1.
+pub fn count_trees(map: &Grid<Square>) -> usize {
+    trees_on_slope(map, (3, 1))
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,12 @@
+use super::{trees_on_slope, Grid, Square, SLOPES};
+
+/// Count the trees encountered on the map while following each of the `SLOPES`, in their order.
+pub fn count_trees(map: &Grid<Square>) -> Vec<usize> {
+    SLOPES
+        .iter()
+        .map(|&slope| trees_on_slope(map, slope))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {}
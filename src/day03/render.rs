@@ -0,0 +1,62 @@
+use super::{Day03, Grid, Square, SLOPES};
+use crate::render::{Frame, Rgb, Visualize};
+use crate::{Part, Result};
+
+const TREE: Rgb = [0x2e, 0x7d, 0x32];
+const HIT: Rgb = [0xd3, 0x2f, 0x2f];
+/// The color of the path down each slope, in turn.
+const PATHS: [Rgb; 5] = [
+    [0x19, 0x76, 0xd2],
+    [0x8e, 0x24, 0xaa],
+    [0xf5, 0x7c, 0x00],
+    [0x00, 0x83, 0x8f],
+    [0x5d, 0x40, 0x37],
+];
+
+/// The map, repeated to the right as far as the toboggan goes, with the path down each slope of
+/// the part and the trees it hits.
+impl Visualize for Day03 {
+    type Input = Grid<Square>;
+
+    fn frames(&self, map: &Self::Input, part: Part) -> Result<Vec<Frame>> {
+        let slopes = match part {
+            Part::One => &SLOPES[1..2],
+            Part::Two => &SLOPES[..],
+        };
+        let paths: Vec<Vec<(usize, usize)>> = slopes
+            .iter()
+            .map(|&(right, down)| {
+                (0..)
+                    .map(|step| (step * down, step * right))
+                    .take_while(|&(row, _)| row < map.height())
+                    .collect()
+            })
+            .collect();
+        let width = paths
+            .iter()
+            .flatten()
+            .map(|&(_, column)| column + 1)
+            .max()
+            .unwrap_or(0)
+            .max(map.width());
+
+        let mut frame = Frame::new();
+        for row in 0..map.height() {
+            for column in 0..width {
+                if map.get_wrapping((row, column)) == Some(&Square::Tree) {
+                    frame.cell((column as i64, row as i64), TREE);
+                }
+            }
+        }
+        for (path, &color) in paths.iter().zip(PATHS.iter().cycle()) {
+            let points = path.iter().map(|&(r, c)| (c as i64, r as i64)).collect();
+            frame.path(points, color);
+        }
+        for &(row, column) in paths.iter().flatten() {
+            if map.get_wrapping((row, column)) == Some(&Square::Tree) {
+                frame.cell((column as i64, row as i64), HIT);
+            }
+        }
+        Ok(vec![frame])
+    }
+}
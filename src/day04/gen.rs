@@ -0,0 +1,84 @@
+use crate::gen::check_size;
+use crate::{Generated, Part, Result, Rng};
+
+use super::FIELDS;
+
+/// A valid value for the given (required) `field`.
+fn valid_value(rng: &mut Rng, field: &str) -> String {
+    match field {
+        "byr" => rng.between(1920, 2002).to_string(),
+        "iyr" => rng.between(2010, 2020).to_string(),
+        "eyr" => rng.between(2020, 2030).to_string(),
+        "hgt" if rng.chance(0.5) => format!("{}cm", rng.between(150, 193)),
+        "hgt" => format!("{}in", rng.between(59, 76)),
+        "hcl" => format!("#{:06x}", rng.between(0, 0xff_ffff)),
+        "ecl" => rng
+            .choose(&["amb", "blu", "brn", "gry", "grn", "hzl", "oth"])
+            .to_string(),
+        "pid" => format!("{:09}", rng.between(0, 999_999_999)),
+        _ => unreachable!("unknown field {:?}", field),
+    }
+}
+
+/// A value for the given (required) `field` that is present, but invalid.
+fn invalid_value(rng: &mut Rng, field: &str) -> String {
+    match field {
+        "byr" => rng.between(1900, 1919).to_string(),
+        "iyr" => rng.between(2021, 2030).to_string(),
+        "eyr" => rng.between(2000, 2019).to_string(),
+        "hgt" => match rng.index(3) {
+            0 => format!("{}cm", rng.between(194, 250)),
+            1 => format!("{}in", rng.between(20, 58)),
+            _ => rng.between(59, 193).to_string(),
+        },
+        "hcl" => format!("{:06x}", rng.between(0, 0xff_ffff)),
+        "ecl" => rng.choose(&["xry", "wat", "zzz"]).to_string(),
+        "pid" => format!("{:08}", rng.between(0, 99_999_999)),
+        _ => unreachable!("unknown field {:?}", field),
+    }
+}
+
+/// Generate `size` passports: about a fifth of them lack a required field, another fifth have a
+/// required field with an invalid value, and the rest are valid.
+///
+/// The fields of each passport are shuffled and spread over one to three lines, and the optional
+/// `cid` field is present in about half of them.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 1)?;
+    let mut records = Vec::with_capacity(size);
+    let (mut present, mut valid) = (0, 0);
+    for _ in 0..size {
+        let kind = rng.index(5);
+        let mut fields: Vec<_> = FIELDS
+            .iter()
+            .map(|&field| (field, valid_value(rng, field)))
+            .collect();
+        match kind {
+            0 => drop(fields.remove(rng.index(fields.len()))),
+            1 => {
+                let i = rng.index(fields.len());
+                fields[i].1 = invalid_value(rng, fields[i].0);
+                present += 1;
+            }
+            _ => {
+                present += 1;
+                valid += 1;
+            }
+        }
+        if rng.chance(0.5) {
+            fields.push(("cid", rng.between(1, 350).to_string()));
+        }
+        rng.shuffle(&mut fields);
+
+        let lines = rng.between(1, 3) as usize;
+        let mut record = vec![vec![]; lines];
+        for (i, (field, value)) in fields.iter().enumerate() {
+            record[i * lines / fields.len()].push(format!("{}:{}", field, value));
+        }
+        let record: Vec<_> = record.iter().map(|tokens| tokens.join(" ")).collect();
+        records.push(record.join("\n") + "\n");
+    }
+    Ok(Generated::new(records.join("\n"))
+        .with_answer(Part::One, present)
+        .with_answer(Part::Two, valid))
+}
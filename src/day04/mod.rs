@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    details, read_groups_with, Details, Explanation, Generated, Malformed, Result, Rng, Solution,
+};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+
+/// The fields that are required for a passport to be considered valid.
+const FIELDS: &[&str] = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+pub type Passport = HashMap<String, String>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Passport>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day04/input.txt"
+    }
+
+    /// Read the passports, each being a blank-line-separated group of `key:value` fields.
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        read_groups_with(path, |lines| {
+            let mut passport = Passport::with_capacity(9);
+            for (i, line) in lines.iter().enumerate() {
+                for token in line.split_whitespace() {
+                    // Byte offset of the token within the line, for diagnostics.
+                    let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+                    let (k, v) = token.split_once(':').ok_or_else(|| {
+                        Malformed::new("expected `<key>:<value>`")
+                            .on_line(i)
+                            .at(offset)
+                    })?;
+                    if passport.insert(k.to_owned(), v.to_owned()).is_some() {
+                        return Err(Malformed::new(format!("duplicate field {:?}", k))
+                            .on_line(i)
+                            .at(offset));
+                    }
+                }
+            }
+            Ok(passport)
+        })
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, passports: &Self::Input) -> Result<Self::Answer1> {
+        Ok(passports.iter().filter(|p| part1::validate(p)).count())
+    }
+
+    fn part2(&self, passports: &Self::Input) -> Result<Self::Answer2> {
+        Ok(passports
+            .iter()
+            .filter(|p| part1::validate(p) && part2::fine_validate(p))
+            .count())
+    }
+
+    fn details1(&self, passports: &Self::Input, _: &Self::Answer1) -> Result<Details> {
+        Ok(details(vec![("passports", passports.len())]))
+    }
+
+    fn details2(&self, passports: &Self::Input, _: &Self::Answer2) -> Result<Details> {
+        Ok(details(vec![("passports", passports.len())]))
+    }
+
+    /// The required fields that each invalid passport is missing.
+    fn explain1(&self, passports: &Self::Input, answer: &Self::Answer1) -> Result<Explanation> {
+        let mut explanation: Explanation = passports
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| !part1::validate(p))
+            .map(|(i, p)| {
+                let missing: Vec<_> = part1::missing_fields(p).collect();
+                format!("passport {}: missing {}", i + 1, missing.join(", "))
+            })
+            .collect();
+        explanation.push(format!(
+            "{} of {} passports have all required fields",
+            answer,
+            passports.len()
+        ));
+        Ok(explanation)
+    }
+
+    /// The rule that each invalid passport breaks first, if it has all required fields at all.
+    fn explain2(&self, passports: &Self::Input, answer: &Self::Answer2) -> Result<Explanation> {
+        let mut explanation = vec![];
+        for (i, passport) in passports.iter().enumerate() {
+            if !part1::validate(passport) {
+                let missing: Vec<_> = part1::missing_fields(passport).collect();
+                explanation.push(format!(
+                    "passport {}: missing {}",
+                    i + 1,
+                    missing.join(", ")
+                ));
+            } else if let Some((field, value, rule)) = part2::broken_rule(passport) {
+                explanation.push(format!(
+                    "passport {}: {} {:?} is not {}",
+                    i + 1,
+                    field,
+                    value,
+                    rule
+                ));
+            }
+        }
+        explanation.push(format!(
+            "{} of {} passports are valid",
+            answer,
+            passports.len()
+        ));
+        Ok(explanation)
+    }
+}
@@ -0,0 +1,18 @@
+use super::{Passport, FIELDS};
+
+/// Return `true` if all required fields are present in the given `passport`.
+pub fn validate(passport: &Passport) -> bool {
+    missing_fields(passport).next().is_none()
+}
+
+/// The required fields that are missing from the given `passport`, in the order they are listed in
+/// the puzzle.
+pub fn missing_fields(passport: &Passport) -> impl Iterator<Item = &'static str> + '_ {
+    FIELDS
+        .iter()
+        .copied()
+        .filter(move |&field| !passport.contains_key(field))
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,59 @@
+use super::Passport;
+
+/// The rule that the value of a required field must follow, as `(field, rule, check)`.
+type Rule = (&'static str, &'static str, fn(&str) -> bool);
+
+/// The rule of each required field, in the order they are listed in the puzzle.
+const RULES: &[Rule] = &[
+    ("byr", "a year in 1920..=2002", |v| {
+        num_in_range(v, 1920, 2002)
+    }),
+    ("iyr", "a year in 2010..=2020", |v| {
+        num_in_range(v, 2010, 2020)
+    }),
+    ("eyr", "a year in 2020..=2030", |v| {
+        num_in_range(v, 2020, 2030)
+    }),
+    ("hgt", "a height in 150..=193cm or 59..=76in", |hgt| {
+        (hgt.ends_with("cm") && num_in_range(&hgt[..hgt.len() - 2], 150, 193))
+            || (hgt.ends_with("in") && num_in_range(&hgt[..hgt.len() - 2], 59, 76))
+    }),
+    ("hcl", "a `#` followed by six hex digits", |hcl| {
+        hcl.len() == 7
+            && hcl.starts_with('#')
+            && hcl[1..]
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+    }),
+    ("ecl", "one of amb, blu, brn, gry, grn, hzl or oth", |ecl| {
+        ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&ecl)
+    }),
+    ("pid", "a nine-digit number", |pid| {
+        pid.len() == 9 && num_in_range(pid, 0, 999_999_999)
+    }),
+];
+
+fn num_in_range(value: &str, lower: u32, upper: u32) -> bool {
+    matches!(value.parse::<u32>(), Ok(num) if num >= lower && num <= upper)
+}
+
+/// Return `true` if the values of all required fields of the given `passport` are valid.
+///
+/// The presence of the required fields must have been checked beforehand.
+pub fn fine_validate(passport: &Passport) -> bool {
+    broken_rule(passport).is_none()
+}
+
+/// The first required field of the given `passport` whose value breaks its rule, if any, as
+/// `(field, value, rule)`.
+///
+/// The presence of the required fields must have been checked beforehand.
+pub fn broken_rule(passport: &Passport) -> Option<(&'static str, &str, &'static str)> {
+    RULES.iter().find_map(|&(field, rule, check)| {
+        let value = passport.get(field).unwrap();
+        (!check(value)).then_some((field, value.as_str(), rule))
+    })
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,39 @@
+use crate::gen::check_size;
+use crate::{Generated, Part, Result, Rng};
+
+/// Encode the given seat ID as a boarding pass of `len` characters: the row in all but the last
+/// three (`F` or `B`), followed by the column (`L` or `R`).
+fn encode(id: u64, len: usize) -> String {
+    (0..len)
+        .rev()
+        .map(|bit| match (bit < 3, id >> bit & 1) {
+            (false, 0) => 'F',
+            (false, _) => 'B',
+            (true, 0) => 'L',
+            (true, _) => 'R',
+        })
+        .collect()
+}
+
+/// Generate `size` boarding passes, whose seat IDs form a contiguous range except for a single
+/// missing one, which is neither at the very front nor at the very back.
+///
+/// Passes are 10 characters long like the real ones, unless more are needed for the seat IDs.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 3)?;
+    let size = size as u64;
+    let first = rng.between(1, size);
+    let last = first + size;
+    let own = rng.between(first + 1, last - 1);
+    let len = (64 - last.leading_zeros() as usize).max(10);
+
+    let mut passes: Vec<_> = (first..=last)
+        .filter(|&id| id != own)
+        .map(|id| encode(id, len))
+        .collect();
+    rng.shuffle(&mut passes);
+    let input: String = passes.iter().map(|pass| pass.clone() + "\n").collect();
+    Ok(Generated::new(input)
+        .with_answer(Part::One, last)
+        .with_answer(Part::Two, own))
+}
@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{read_parsed_with, Explanation, Generated, Malformed, Result, Rng, Solution};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day05/input.txt"
+    }
+
+    /// Decode each boarding pass into its seat ID, which is just the pass read as a binary number.
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        read_parsed_with(path, |line| {
+            if line.is_empty() || line.len() > 63 {
+                return Err(Malformed::new("expected 1 to 63 of `F`, `B`, `L` or `R`"));
+            }
+            line.char_indices().try_fold(0, |id, (i, c)| match c {
+                'F' | 'L' => Ok(id << 1),
+                'B' | 'R' => Ok((id << 1) | 1),
+                _ => Err(Malformed::new("expected one of `F`, `B`, `L` or `R`").at(i)),
+            })
+        })
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, seats: &Self::Input) -> Result<Self::Answer1> {
+        part1::highest_seat(seats)
+    }
+
+    fn part2(&self, seats: &Self::Input) -> Result<Self::Answer2> {
+        part2::find_own_seat(seats)
+    }
+
+    /// The missing seat, between its two taken neighbours.
+    fn explain2(&self, _: &Self::Input, seat: &Self::Answer2) -> Result<Explanation> {
+        // The last three letters of each pass pick the column, and the rest the row.
+        let place = |id: u64| format!("{} (row {}, column {})", id, id >> 3, id & 7);
+        Ok(vec![
+            format!("seat {} is missing", place(*seat)),
+            format!(
+                "while its neighbours {} and {} are taken",
+                place(seat - 1),
+                place(seat + 1)
+            ),
+        ])
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{Error, Result};
+
+pub fn highest_seat(seats: &[u64]) -> Result<u64> {
+    seats
+        .iter()
+        .copied()
+        .max()
+        .ok_or_else(|| Error::InvalidInput("no boarding passes".to_owned()))
+}
+
+#[cfg(test)]
+mod tests {}
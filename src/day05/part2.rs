@@ -0,0 +1,44 @@
+use std::collections::BTreeSet;
+
+use crate::{Error, Result};
+
+/// Find the single missing seat ID, whose neighbours (`ID - 1` and `ID + 1`) are both taken.
+///
+/// If there are several such gaps, the one with the lowest ID is returned.
+pub fn find_own_seat(seats: &[u64]) -> Result<u64> {
+    let seats: BTreeSet<_> = seats.iter().copied().collect();
+    seats
+        .iter()
+        .zip(seats.iter().skip(1))
+        .find(|&(prev, next)| next - prev == 2)
+        .map(|(prev, _)| prev + 1)
+        .ok_or_else(|| Error::NoSolution("no empty seat found".to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    /// Reference: check every ID between the lowest and the highest taken, in O(n^2).
+    fn find_own_seat_naive(seats: &[u64]) -> Option<u64> {
+        let (&min, &max) = (seats.iter().min()?, seats.iter().max()?);
+        (min + 1..max).find(|id| {
+            !seats.contains(id) && seats.contains(&(id - 1)) && seats.contains(&(id + 1))
+        })
+    }
+
+    #[test]
+    fn same_as_naive() {
+        let mut rng = Rng::new(5);
+        for _ in 0..2000 {
+            let len = rng.between(0, 10) as usize;
+            let seats: Vec<_> = (0..len).map(|_| rng.between(0, 12)).collect();
+            let expected = find_own_seat_naive(&seats);
+            match find_own_seat(&seats) {
+                Ok(seat) => assert_eq!(Some(seat), expected, "{:?}", seats),
+                Err(_) => assert_eq!(expected, None, "{:?}", seats),
+            }
+        }
+    }
+}
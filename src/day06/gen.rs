@@ -0,0 +1,43 @@
+use crate::gen::check_size;
+use crate::{Generated, Part, Result, Rng};
+
+/// Generate `size` groups of one to five people each, who answered "yes" to a random (non-empty)
+/// set of questions.
+///
+/// The questions of a group are biased towards a few common ones, so that everyone in it answers
+/// some of them.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 1)?;
+    let mut groups = Vec::with_capacity(size);
+    let (mut anyone, mut everyone) = (0, 0);
+    for _ in 0..size {
+        let common = rng.next_u64() as u32 & rng.next_u64() as u32 & ((1 << 26) - 1);
+        let people: Vec<u32> = (0..rng.between(1, 5))
+            .map(|_| loop {
+                let answers =
+                    (common | rng.next_u64() as u32 & rng.next_u64() as u32) & ((1 << 26) - 1);
+                if answers != 0 {
+                    break answers;
+                }
+            })
+            .collect();
+        anyone += people.iter().fold(0, |acc, p| acc | p).count_ones();
+        everyone += people.iter().fold(u32::MAX, |acc, p| acc & p).count_ones();
+
+        let lines: Vec<String> = people
+            .iter()
+            .map(|&answers| {
+                let mut questions: Vec<_> = (0..26)
+                    .filter(|q| answers >> q & 1 == 1)
+                    .map(|q| (b'a' + q as u8) as char)
+                    .collect();
+                rng.shuffle(&mut questions);
+                questions.into_iter().collect()
+            })
+            .collect();
+        groups.push(lines.join("\n") + "\n");
+    }
+    Ok(Generated::new(groups.join("\n"))
+        .with_answer(Part::One, anyone)
+        .with_answer(Part::Two, everyone))
+}
@@ -0,0 +1,60 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{read_groups_with, Generated, Malformed, Result, Rng, Solution};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+
+/// The answers of each person in a group; one line per person.
+pub type Group = Vec<String>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Group>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day06/input.txt"
+    }
+
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        read_groups_with(path, |lines| {
+            for (i, line) in lines.iter().enumerate() {
+                if let Some(offset) = line.find(|c: char| !c.is_ascii_lowercase()) {
+                    return Err(Malformed::new("expected questions `a` through `z`")
+                        .on_line(i)
+                        .at(offset));
+                }
+            }
+            Ok(lines.to_vec())
+        })
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, groups: &Self::Input) -> Result<Self::Answer1> {
+        Ok(groups
+            .iter()
+            .map(|group| part1::anyone_answered(group))
+            .sum())
+    }
+
+    fn part2(&self, groups: &Self::Input) -> Result<Self::Answer2> {
+        Ok(groups
+            .iter()
+            .map(|group| part2::everyone_answered(group))
+            .sum())
+    }
+}
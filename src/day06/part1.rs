@@ -0,0 +1,15 @@
+use std::collections::HashSet;
+
+/// Count the questions to which anyone in the `group` answered "yes".
+pub fn anyone_answered(group: &[String]) -> usize {
+    let mut group_answered: HashSet<_> = HashSet::with_capacity(26);
+    group.iter().for_each(|line| {
+        line.chars().for_each(|q| {
+            group_answered.insert(q);
+        })
+    });
+    group_answered.len()
+}
+
+#[cfg(test)]
+mod tests {}
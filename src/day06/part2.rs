@@ -0,0 +1,27 @@
+/// Count the questions to which everyone in the `group` answered "yes", however many times each
+/// person answered them.
+pub fn everyone_answered(group: &[String]) -> usize {
+    // One bit per question, from `a` up.
+    let answered = |line: &String| line.bytes().fold(0u32, |set, c| set | 1 << (c - b'a'));
+    group
+        .iter()
+        .map(answered)
+        .fold(!0, |everyone, person| everyone & person)
+        .count_ones() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn everyone() {
+        let group = |lines: &[&str]| lines.iter().map(|&l| l.to_owned()).collect::<Vec<_>>();
+        assert_eq!(everyone_answered(&group(&["abc"])), 3);
+        assert_eq!(everyone_answered(&group(&["a", "b", "c"])), 0);
+        assert_eq!(everyone_answered(&group(&["ab", "ac"])), 1);
+        assert_eq!(everyone_answered(&group(&["b"; 4])), 1);
+        assert_eq!(everyone_answered(&group(&["aa", "b"])), 0);
+        assert_eq!(everyone_answered(&group(&["aab", "ba"])), 2);
+    }
+}
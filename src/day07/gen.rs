@@ -0,0 +1,98 @@
+use crate::gen::check_size;
+use crate::{Generated, Part, Result, Rng};
+
+use super::TARGET;
+
+const ADJECTIVES: &[&str] = &[
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy",
+];
+
+const COLORS: &[&str] = &[
+    "aqua", "beige", "black", "blue", "bronze", "brown", "coral", "crimson", "cyan", "fuchsia",
+    "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive", "orange",
+    "plum", "purple", "red", "salmon", "silver", "tan", "teal", "tomato", "violet", "white",
+    "yellow",
+];
+
+/// The number of bags after [`TARGET`] in the topological order of the rules, which bounds the
+/// depth of the bags it contains (and thus their count).
+const DEPTH: usize = 11;
+
+/// Generate `size` rules, one per bag color, that form a directed acyclic graph.
+///
+/// The colors are put in a random order, with each bag containing up to three (1 to 4 each) of
+/// the bags that come after it; [`TARGET`] is placed among the last few, so that the number of
+/// bags within it stays far from overflowing.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 1)?;
+    let mut bags: Vec<String> = (0..)
+        .flat_map(|level: usize| {
+            ADJECTIVES.iter().flat_map(move |adjective| {
+                COLORS.iter().map(move |color| match level {
+                    0 => format!("{} {}", adjective, color),
+                    _ => format!("{}{} {}", adjective, level, color),
+                })
+            })
+        })
+        .filter(|bag| bag != TARGET)
+        .take(size - 1)
+        .collect();
+    rng.shuffle(&mut bags);
+    let target = size.saturating_sub(DEPTH + 1);
+    bags.insert(target, TARGET.to_owned());
+
+    let contents: Vec<Vec<(u64, usize)>> = (0..size)
+        .map(|i| {
+            let mut contents: Vec<(u64, usize)> = vec![];
+            for _ in 0..rng.between(0, 3).min((size - i - 1) as u64) {
+                let j = loop {
+                    let j = rng.between(i as u64 + 1, size as u64 - 1) as usize;
+                    if contents.iter().all(|&(_, k)| k != j) {
+                        break j;
+                    }
+                };
+                contents.push((rng.between(1, 4), j));
+            }
+            contents
+        })
+        .collect();
+
+    // Both answers follow from the contents of the bags after each bag, in reverse order; only
+    // the bags after the target may be within it, though.
+    let mut holds_target = vec![false; size];
+    let mut within = vec![0; size];
+    for i in (0..size).rev() {
+        holds_target[i] = contents[i]
+            .iter()
+            .any(|&(_, j)| j == target || holds_target[j]);
+        if i >= target {
+            within[i] = contents[i]
+                .iter()
+                .map(|&(quantity, j)| quantity * (1 + within[j]))
+                .sum::<u64>();
+        }
+    }
+
+    let mut rules: Vec<_> = bags
+        .iter()
+        .zip(&contents)
+        .map(|(bag, contents)| {
+            let contents: Vec<_> = contents
+                .iter()
+                .map(|&(quantity, j)| {
+                    let plural = if quantity == 1 { "" } else { "s" };
+                    format!("{} {} bag{}", quantity, bags[j], plural)
+                })
+                .collect();
+            match contents.as_slice() {
+                [] => format!("{} bags contain no other bags.\n", bag),
+                _ => format!("{} bags contain {}.\n", bag, contents.join(", ")),
+            }
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+    Ok(Generated::new(rules.concat())
+        .with_answer(Part::One, holds_target.iter().filter(|&&b| b).count())
+        .with_answer(Part::Two, within[target]))
+}
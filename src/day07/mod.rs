@@ -0,0 +1,305 @@
+use std::borrow::Cow;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{read_parsed_with, Error, Generated, Malformed, Result, Rng, Solution};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+struct BagVertex<K> {
+    containable: Vec<(usize, K)>,
+}
+
+impl<K> BagVertex<K> {
+    fn new(containable: Vec<(usize, K)>) -> Self {
+        BagVertex { containable }
+    }
+}
+
+#[derive(Debug)]
+pub struct BagGraph<K> {
+    bags: HashMap<K, BagVertex<K>>,
+}
+
+impl<K: Eq + Hash + Clone + Debug> BagGraph<K> {
+    #[inline]
+    fn new<II>(iterable: II) -> Self
+    where
+        II: IntoIterator<Item = (K, Vec<(usize, K)>)>,
+    {
+        let mut graph = HashMap::new();
+        iterable.into_iter().for_each(|(subject_bag, object_bags)| {
+            graph.insert(subject_bag, BagVertex::new(object_bags));
+        });
+        Self { bags: graph }
+    }
+
+    /// Return an error if any bag is said to contain a bag for which there is no rule, so that
+    /// traversals never step outside the graph.
+    fn check_closed(&self) -> Result<()> {
+        for (bag, vertex) in &self.bags {
+            if let Some((_, missing)) = vertex
+                .containable
+                .iter()
+                .find(|(_, child)| !self.bags.contains_key(child))
+            {
+                return Err(Error::InvalidInput(format!(
+                    "{:?} bags contain {:?} bags, for which there is no rule",
+                    bag, missing
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Return an error if there is no rule for the given `bag`.
+    fn check_contains(&self, bag: &K) -> Result<()> {
+        if !self.bags.contains_key(bag) {
+            return Err(Error::InvalidInput(format!("no rule for {:?} bags", bag)));
+        }
+        Ok(())
+    }
+}
+
+/// A rule being parsed from left to right, up to the given byte `offset` of its `line`.
+struct RuleParser<'a> {
+    line: &'a str,
+    offset: usize,
+}
+
+impl<'a> RuleParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.line[self.offset..]
+    }
+
+    /// Skip the given `literal`, or fail pointing at where it was expected.
+    fn expect(&mut self, literal: &str) -> Result<(), Malformed> {
+        if !self.rest().starts_with(literal) {
+            return Err(Malformed::new(format!("expected `{}`", literal)).at(self.offset));
+        }
+        self.offset += literal.len();
+        Ok(())
+    }
+
+    /// Take the longest non-empty run of characters that satisfy `pred`, or fail saying that
+    /// `what` was expected.
+    fn take(&mut self, what: &str, pred: fn(char) -> bool) -> Result<&'a str, Malformed> {
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(Malformed::new(format!("expected {}", what)).at(self.offset));
+        }
+        self.offset += len;
+        Ok(&rest[..len])
+    }
+
+    /// `<adjective> <color> bag` (leaving any plural `s`), as `<adjective> <color>`.
+    fn bag(&mut self) -> Result<String, Malformed> {
+        let adjective = self.take("an adjective", char::is_alphanumeric)?;
+        self.expect(" ")?;
+        let color = self.take("a color", char::is_alphanumeric)?;
+        self.expect(" bag")?;
+        Ok(format!("{} {}", adjective, color))
+    }
+}
+
+/// Parse a whole rule, i.e. `<adjective> <color> bags contain <contents>.`, where the contents are
+/// either `no other bags` or a list of `<n> <adjective> <color> bag(s)` separated by `, `.
+fn parse_rule(line: &str) -> Result<(String, Vec<(usize, String)>), Malformed> {
+    let mut rule = RuleParser { line, offset: 0 };
+    let bag = rule.bag()?;
+    rule.expect("s contain ")?;
+    let mut contents = vec![];
+    if rule.rest().starts_with("no ") {
+        rule.expect("no other bags")?;
+        rule.expect(".")?;
+    } else {
+        loop {
+            let start = rule.offset;
+            let quantity = rule.take("a number", |c| c.is_ascii_digit())?;
+            let quantity = quantity
+                .parse()
+                .map_err(|err| Malformed::from(err).at(start))?;
+            rule.expect(" ")?;
+            contents.push((quantity, rule.bag()?));
+            if rule.rest().starts_with('s') {
+                rule.offset += 1;
+            }
+            if rule.rest().starts_with(", ") {
+                rule.offset += 2;
+            } else if rule.rest().starts_with('.') {
+                rule.offset += 1;
+                break;
+            } else {
+                return Err(Malformed::new("expected `, ` or `.`").at(rule.offset));
+            }
+        }
+    }
+    if !rule.rest().is_empty() {
+        return Err(Malformed::new("expected the end of the rule").at(rule.offset));
+    }
+    Ok((bag, contents))
+}
+
+/// The bag that the puzzle is about by default, which the generated inputs are built for.
+const TARGET: &str = "shiny gold";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day07 {
+    /// The bag that both parts of the puzzle are about.
+    pub bag: Cow<'static, str>,
+}
+
+impl Day07 {
+    pub const DEFAULT: Day07 = Day07 {
+        bag: Cow::Borrowed(TARGET),
+    };
+}
+
+impl Solution for Day07 {
+    type Input = BagGraph<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day07/input.txt"
+    }
+
+    /// Read the rules, one per line; a bag may only have a single rule.
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        // The line of the rule for each bag so far.
+        let mut rules = HashMap::new();
+        let mut line_number = 0;
+        let graph = BagGraph::new(read_parsed_with(path, |line| {
+            line_number += 1;
+            let (bag, contents) = parse_rule(line)?;
+            match rules.entry(bag.clone()) {
+                Entry::Occupied(earlier) => Err(Malformed::new(format!(
+                    "there is already a rule for {:?} bags, on line {}",
+                    bag,
+                    earlier.get()
+                ))),
+                Entry::Vacant(entry) => {
+                    entry.insert(line_number);
+                    Ok((bag, contents))
+                }
+            }
+        })?);
+        graph.check_closed()?;
+        Ok(graph)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, graph: &Self::Input) -> Result<Self::Answer1> {
+        let target = self.bag.to_string();
+        graph.check_contains(&target)?;
+        Ok(graph
+            .bags
+            .keys()
+            .filter(|bag| graph.dfs_search(bag, &target))
+            .count()
+            - 1) // Subtract the "shiny gold"-->"shiny gold" case that will have been included
+    }
+
+    fn part2(&self, graph: &Self::Input) -> Result<Self::Answer2> {
+        let target = self.bag.to_string();
+        graph.check_contains(&target)?;
+        graph.dfs_count_bags(&target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+    use crate::Puzzle;
+
+    #[test]
+    fn parse_whole_rules() {
+        assert_eq!(
+            parse_rule("light red bags contain 1 bright white bag, 2 muted yellow bags."),
+            Ok((
+                "light red".to_owned(),
+                vec![
+                    (1, "bright white".to_owned()),
+                    (2, "muted yellow".to_owned())
+                ]
+            ))
+        );
+        assert_eq!(
+            parse_rule("faded blue bags contain no other bags."),
+            Ok(("faded blue".to_owned(), vec![]))
+        );
+        for (line, offset) in [
+            ("garbage here", 12),
+            ("xx yy bags contain 3x foo.", 20),
+            ("faded blue bags contain no other bags", 37),
+            (
+                "light red bags contain 1 bright white bag; 2 muted yellow bags.",
+                41,
+            ),
+            ("light red bags contain 1 bright white bag.!", 42),
+        ] {
+            assert_eq!(
+                parse_rule(line).map_err(|err| err.offset),
+                Err(offset),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn check_rules() {
+        let dir = TempDir::new("day07");
+        let path = dir.join("input.txt");
+        std::fs::write(
+            &path,
+            "garbage here\n\
+             xx yy bags contain 3x foo.\n\
+             faded blue bags contain no other bags.\n\
+             faded blue bags contain 1 faded blue bag.\n",
+        )
+        .unwrap();
+        let errors: Vec<_> = Puzzle::check(&Day07::DEFAULT, &path)
+            .into_iter()
+            .map(|err| match err {
+                Error::Parse {
+                    line,
+                    column,
+                    reason,
+                    ..
+                } => (line, column, reason),
+                err => panic!("unexpected error {:?}", err),
+            })
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                (1, 13, "expected ` bag`".to_owned()),
+                (2, 21, "expected ` `".to_owned()),
+                (
+                    4,
+                    1,
+                    "there is already a rule for \"faded blue\" bags, on line 3".to_owned()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use tracing::{debug, trace};
+
+use super::BagGraph;
+
+impl<K: Eq + Hash + Clone + Debug> BagGraph<K> {
+    /// Traversing the `BagGraph` in a DFS manner, return `true` if `target` vertex is reachable
+    /// from `start` vertex or `false` otherwise.
+    pub fn dfs_search(&self, start: &K, target: &K) -> bool {
+        let mut stack = Vec::with_capacity(self.bags.len());
+        stack.push(start);
+        let mut visited = HashSet::with_capacity(self.bags.len());
+        visited.insert(start);
+        while let Some(curr_key) = stack.pop() {
+            trace!(bag = ?curr_key, "visit");
+            if curr_key.eq(target) {
+                // TODO: Memoize this here and add a check for it right above for O(V + E).
+                debug!(start = ?start, visited = visited.len(), "found");
+                return true;
+            }
+            let curr_vertex = self.bags.get(curr_key).unwrap();
+            curr_vertex.containable.iter().for_each(|(_, adj_key)| {
+                if !visited.contains(adj_key) {
+                    stack.push(adj_key);
+                    visited.insert(adj_key);
+                }
+            })
+        }
+        debug!(start = ?start, visited = visited.len(), "not found");
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {}
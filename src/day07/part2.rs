@@ -0,0 +1,116 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::slice;
+
+use tracing::trace;
+
+use super::BagGraph;
+use crate::{Error, Result};
+
+/// A bag on the path of [`BagGraph::dfs_count_bags`]: how many of it its parent contains, the
+/// contents left to count, and the bags counted within it so far.
+struct Counting<'a, K> {
+    bag: &'a K,
+    quantity: usize,
+    children: slice::Iter<'a, (usize, K)>,
+    count: usize,
+}
+
+impl<K: Eq + Hash + Clone + Debug> BagGraph<K> {
+    fn counting<'a>(&'a self, bag: &'a K, quantity: usize) -> Counting<'a, K> {
+        Counting {
+            bag,
+            quantity,
+            children: self.bags[bag].containable.iter(),
+            count: 0,
+        }
+    }
+
+    /// Traversing the `BagGraph` in a DFS manner, return the total number of bags that are
+    /// recursively contained in bag `start`, or an error if it contains itself.
+    ///
+    /// The path is kept on a stack of its own rather than by recursing, so that however long a
+    /// chain of rules is, it cannot overflow the stack of the thread.
+    pub fn dfs_count_bags(&self, start: &K) -> Result<usize> {
+        // The bags counted in full, and those on the path to the one being counted.
+        let mut counts: HashMap<&K, usize> = HashMap::with_capacity(self.bags.len());
+        let mut on_path = HashSet::new();
+        on_path.insert(start);
+        let mut path = vec![self.counting(start, 1)];
+        loop {
+            let top = path.last_mut().unwrap();
+            match top.children.next() {
+                Some((quantity, child)) => match counts.get(child) {
+                    Some(count) => top.count += quantity * (1 + count),
+                    None if on_path.contains(child) => {
+                        let i = path.iter().position(|counting| counting.bag == child);
+                        let cycle: Vec<_> = path[i.unwrap()..]
+                            .iter()
+                            .map(|counting| format!("{:?}", counting.bag))
+                            .chain(std::iter::once(format!("{:?}", child)))
+                            .collect();
+                        return Err(Error::InvalidInput(format!(
+                            "bags contain themselves: {}",
+                            cycle.join(" -> ")
+                        )));
+                    }
+                    None => {
+                        on_path.insert(child);
+                        path.push(self.counting(child, *quantity));
+                    }
+                },
+                None => {
+                    let done = path.pop().unwrap();
+                    trace!(bag = ?done.bag, count = done.count, "counted");
+                    on_path.remove(done.bag);
+                    counts.insert(done.bag, done.count);
+                    match path.last_mut() {
+                        Some(parent) => parent.count += done.quantity * (1 + done.count),
+                        None => return Ok(done.count),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reject_cycles() {
+        let graph = BagGraph::new(vec![
+            ("shiny gold", vec![(1, "dark red")]),
+            ("dark red", vec![(2, "shiny gold")]),
+        ]);
+        let err = graph.dfs_count_bags(&"shiny gold").unwrap_err().to_string();
+        assert!(
+            err.contains(r#"bags contain themselves: "shiny gold" -> "dark red" -> "shiny gold""#),
+            "{}",
+            err
+        );
+
+        let graph = BagGraph::new(vec![
+            ("shiny gold", vec![(1, "dark red"), (2, "dark orange")]),
+            ("dark red", vec![(2, "dark orange")]),
+            ("dark orange", vec![]),
+            ("faded blue", vec![(1, "faded blue")]),
+        ]);
+        // The cycle is out of reach of the shiny gold bag.
+        assert_eq!(graph.dfs_count_bags(&"shiny gold").unwrap(), 1 + 2 + 2);
+        assert!(graph.dfs_count_bags(&"faded blue").is_err());
+    }
+
+    /// A chain of rules far deeper than recursing could go without overflowing the stack.
+    #[test]
+    fn deep_chain() {
+        const DEPTH: usize = 100_000;
+        let graph = BagGraph::new((0..DEPTH).map(|i| match i + 1 {
+            next if next < DEPTH => (i, vec![(1, next)]),
+            _ => (i, vec![]),
+        }));
+        assert_eq!(graph.dfs_count_bags(&0).unwrap(), DEPTH - 1);
+    }
+}
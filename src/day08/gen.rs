@@ -0,0 +1,83 @@
+use crate::gen::check_size;
+use crate::{Generated, Part, Result, Rng};
+
+use super::Instruction;
+
+/// A random `acc` instruction.
+fn acc(rng: &mut Rng) -> Instruction {
+    Instruction::Acc(rng.between(0, 100) as i32 - 50)
+}
+
+/// A random instruction that is never executed, whatever the fix.
+fn junk(rng: &mut Rng) -> Instruction {
+    let argument = rng.between(0, 40) as isize - 20;
+    match rng.index(3) {
+        0 => acc(rng),
+        1 => Instruction::Nop(argument),
+        _ => Instruction::Jmp(argument),
+    }
+}
+
+/// Generate a program of `size` instructions, which loops because of exactly one corrupted `jmp`.
+///
+/// The program runs straight up to the corrupted instruction, which jumps back into the part that
+/// has been executed; every `nop` and `jmp` before it moves to the next instruction whether it is
+/// patched or not, so that patching any of them still loops. Patched into a `nop`, it lets the
+/// program go on and terminate by jumping forward only, over instructions that are never executed.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 2)?;
+    let corrupted = rng.between(1, (size as u64 / 2).max(1)) as usize;
+    let mut code = Vec::with_capacity(size);
+    let (mut before, mut after) = (0, 0);
+    for _ in 0..corrupted {
+        code.push(match rng.index(4) {
+            0 => Instruction::Nop(rng.between(0, 1) as isize),
+            1 => Instruction::Jmp(1),
+            _ => acc(rng),
+        });
+    }
+    code.push(Instruction::Jmp(
+        -(rng.between(1, corrupted as u64) as isize),
+    ));
+    while code.len() < size {
+        let remaining = size - code.len();
+        match rng.index(8) {
+            0 | 1 if remaining > 1 => {
+                let offset = rng.between(2, remaining.min(6) as u64) as usize;
+                code.push(Instruction::Jmp(offset as isize));
+                for _ in 1..offset {
+                    code.push(junk(rng));
+                }
+            }
+            2 => code.push(Instruction::Nop(rng.between(0, 40) as isize - 20)),
+            _ => code.push(acc(rng)),
+        }
+    }
+
+    // The instructions that are executed are the ones that are not jumped over.
+    let mut ip = 0;
+    while ip < size {
+        match code[ip] {
+            Instruction::Acc(a) if ip < corrupted => before += a,
+            Instruction::Acc(a) => after += a,
+            Instruction::Jmp(offset) if ip > corrupted => {
+                ip += offset as usize;
+                continue;
+            }
+            _ => (),
+        }
+        ip += 1;
+    }
+
+    let input: String = code
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Nop(argument) => format!("nop {:+}\n", argument),
+            Instruction::Acc(argument) => format!("acc {:+}\n", argument),
+            Instruction::Jmp(argument) => format!("jmp {:+}\n", argument),
+        })
+        .collect();
+    Ok(Generated::new(input)
+        .with_answer(Part::One, before)
+        .with_answer(Part::Two, before + after))
+}
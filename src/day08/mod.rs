@@ -0,0 +1,217 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, trace};
+
+use crate::{read_parsed, Error, Explanation, Generated, Malformed, Result, Rng, Solution};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Nop(isize),
+    Acc(i32),
+    Jmp(isize),
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = Malformed;
+
+    /// Parse an instruction of the form `<operation> <argument>`, e.g. `jmp -4`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (operation, argument) = s
+            .split_once(' ')
+            .ok_or_else(|| Malformed::new("expected `<operation> <argument>`").at(s.len()))?;
+        let offset = operation.len() + 1;
+        let malformed_argument = |err| Malformed::from(err).at(offset);
+        match operation {
+            "nop" => Ok(Instruction::Nop(
+                argument.parse().map_err(malformed_argument)?,
+            )),
+            "acc" => Ok(Instruction::Acc(
+                argument.parse().map_err(malformed_argument)?,
+            )),
+            "jmp" => Ok(Instruction::Jmp(
+                argument.parse().map_err(malformed_argument)?,
+            )),
+            token => Err(Malformed::new(format!("unknown operation {:?}", token))),
+        }
+    }
+}
+
+/// Each instruction as it is written in the program, e.g. `jmp -4`.
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Nop(offset) => write!(f, "nop {:+}", offset),
+            Instruction::Acc(a) => write!(f, "acc {:+}", a),
+            Instruction::Jmp(offset) => write!(f, "jmp {:+}", offset),
+        }
+    }
+}
+
+/// How running a program on the [`Console`] ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    /// An instruction is about to be executed a second time.
+    Loop,
+    /// The instruction right after the last one is about to be executed.
+    Terminated,
+}
+
+/// The handheld game console, in the middle of running a program.
+#[derive(Debug, Clone, Default)]
+pub struct Console {
+    pub ip: isize,
+    pub acc: i32,
+    executed: HashSet<isize>,
+}
+
+impl Console {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Execute the instruction at `ip`, and return how the program exits right after it (if it
+    /// does).
+    ///
+    /// An error is returned if `ip` lies outside the `code` (other than right after its end).
+    pub fn step(&mut self, code: &[Instruction]) -> Result<Option<Exit>> {
+        self.executed.insert(self.ip);
+        let instruction = code.get(self.ip as usize);
+        if let Some(instruction) = instruction {
+            trace!(ip = self.ip, acc = self.acc, %instruction, "step");
+        }
+        match instruction {
+            Some(&Instruction::Nop(_)) => self.ip += 1,
+            Some(&Instruction::Acc(a)) => {
+                self.acc += a;
+                self.ip += 1;
+            }
+            Some(&Instruction::Jmp(offset)) => self.ip += offset,
+            None => {
+                return Err(Error::InvalidInput(format!(
+                    "ip out of bounds ({} not in 0..{})",
+                    self.ip,
+                    code.len()
+                )));
+            }
+        }
+        let exit = if self.ip == code.len() as isize {
+            Some(Exit::Terminated)
+        } else if self.executed.contains(&self.ip) {
+            Some(Exit::Loop)
+        } else {
+            None
+        };
+        if let Some(exit) = exit {
+            debug!(ip = self.ip, acc = self.acc, ?exit, "exit");
+        }
+        Ok(exit)
+    }
+
+    /// Keep executing the `code` until the program exits.
+    pub fn run(&mut self, code: &[Instruction]) -> Result<Exit> {
+        loop {
+            if let Some(exit) = self.step(code)? {
+                return Ok(exit);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day08/input.txt"
+    }
+
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        read_parsed(path)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, code: &Self::Input) -> Result<Self::Answer1> {
+        part1::acc_before_loop(code)
+    }
+
+    fn part2(&self, code: &Self::Input) -> Result<Self::Answer2> {
+        part2::fix_and_run(&mut code.clone())
+    }
+
+    /// The corrupted instruction, and what it was patched into.
+    fn explain2(&self, code: &Self::Input, acc: &Self::Answer2) -> Result<Explanation> {
+        let mut patched = code.clone();
+        let explanation = match part2::patch(&mut patched)? {
+            (Some(pos), _) => {
+                let fixed = match code[pos] {
+                    Instruction::Nop(offset) => Instruction::Jmp(offset),
+                    Instruction::Jmp(offset) => Instruction::Nop(offset),
+                    instruction => instruction,
+                };
+                vec![
+                    format!("line {}: patched `{}` into `{}`", pos + 1, code[pos], fixed),
+                    format!(
+                        "the program then terminates with {} in the accumulator",
+                        acc
+                    ),
+                ]
+            }
+            (None, _) => vec![format!(
+                "the program terminates without patching, with {} in the accumulator",
+                acc
+            )],
+        };
+        Ok(explanation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_instruction() {
+        assert!(matches!("jmp -4".parse(), Ok(Instruction::Jmp(-4))));
+        assert!(matches!("acc +7".parse(), Ok(Instruction::Acc(7))));
+        assert_eq!(Instruction::Jmp(-4).to_string(), "jmp -4");
+        assert_eq!(Instruction::Acc(7).to_string(), "acc +7");
+        assert_eq!(
+            "no".parse::<Instruction>().unwrap_err(),
+            Malformed::new("expected `<operation> <argument>`").at(2)
+        );
+        assert_eq!("acc x".parse::<Instruction>().unwrap_err().offset, 4);
+        assert_eq!("mul +1".parse::<Instruction>().unwrap_err().offset, 0);
+    }
+
+    #[test]
+    fn console() {
+        use Instruction::*;
+
+        let mut console = Console::new();
+        assert_eq!(console.run(&[Acc(3), Jmp(-1)]).unwrap(), Exit::Loop);
+        assert_eq!((console.ip, console.acc), (0, 3));
+
+        let mut console = Console::new();
+        assert_eq!(console.run(&[Nop(0), Acc(-2)]).unwrap(), Exit::Terminated);
+        assert_eq!((console.ip, console.acc), (2, -2));
+
+        assert!(Console::new().run(&[Jmp(5)]).is_err());
+    }
+}
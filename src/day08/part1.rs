@@ -0,0 +1,18 @@
+use crate::{Error, Result};
+
+use super::{Console, Exit, Instruction};
+
+/// Run the program until any instruction is about to be executed a second time, and return the
+/// value of the accumulator at that point.
+pub fn acc_before_loop(code: &[Instruction]) -> Result<i32> {
+    let mut console = Console::new();
+    match console.run(code)? {
+        Exit::Loop => Ok(console.acc),
+        Exit::Terminated => Err(Error::NoSolution(
+            "the program terminates without looping".to_owned(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,52 @@
+use tracing::debug;
+
+use crate::{Error, Result};
+
+use super::{Console, Exit, Instruction};
+
+/// Find the single corrupted `nop` or `jmp` instruction that keeps the program from terminating,
+/// patch it and return the value of the accumulator after the program terminates.
+pub fn fix_and_run(code: &mut [Instruction]) -> Result<i32> {
+    patch(code).map(|(_, acc)| acc)
+}
+
+/// Like [`fix_and_run`], but also return the index of the corrupted instruction, if the program
+/// does not terminate as it is.
+///
+/// Only the instructions executed before the program loops may be the corrupted one, and the
+/// patched program is resumed from the state the console was in when it got to it.
+pub fn patch(code: &mut [Instruction]) -> Result<(Option<usize>, i32)> {
+    let mut console = Console::new();
+    let mut alt_execs = Vec::new();
+    loop {
+        let alt_instr = match code.get(console.ip as usize) {
+            Some(&Instruction::Nop(offset)) => Some(Instruction::Jmp(offset)),
+            Some(&Instruction::Jmp(offset)) => Some(Instruction::Nop(offset)),
+            _ => None,
+        };
+        if let Some(alt_instr) = alt_instr {
+            alt_execs.push(((console.ip as usize, alt_instr), console.clone()));
+        }
+        match console.step(code)? {
+            Some(Exit::Terminated) => return Ok((None, console.acc)),
+            Some(Exit::Loop) => break,
+            None => (),
+        }
+    }
+
+    for ((pos, alt_instr), mut console) in alt_execs {
+        let orig_instr = std::mem::replace(&mut code[pos], alt_instr);
+        let exit = console.run(code);
+        code[pos] = orig_instr;
+        debug!(line = pos + 1, patched = %alt_instr, ?exit, "patch");
+        if let Ok(Exit::Terminated) = exit {
+            return Ok((Some(pos), console.acc));
+        }
+    }
+    Err(Error::NoSolution(
+        "patching any single instruction does not make the program terminate".to_owned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,127 @@
+use crate::gen::check_size;
+use crate::{Error, Generated, Part, Result, Rng};
+
+use super::PREAMBLE;
+
+/// The most numbers before the invalid one. Since every number there is the sum of two before it,
+/// even the smallest ones double every `PREAMBLE` numbers or so, which overflows after about 1500.
+const MAX_VALID: usize = 1000;
+
+/// A number that is the sum of two different numbers in `window`; two of the smallest ones, to
+/// keep the numbers from growing faster than they have to. `count` is that of the numbers so far.
+fn valid_number(rng: &mut Rng, window: &[i64], count: usize) -> Result<i64> {
+    let mut smallest = window.to_vec();
+    smallest.sort_unstable();
+    smallest.dedup();
+    smallest.truncate(4);
+    let i = rng.index(smallest.len() - 1);
+    let j = rng.between(i as u64 + 1, smallest.len() as u64 - 1) as usize;
+    smallest[i]
+        .checked_add(smallest[j])
+        .ok_or_else(|| overflow(count))
+}
+
+fn overflow(len: usize) -> Error {
+    Error::InvalidInput(format!(
+        "the numbers overflow after {} of them; try a smaller size",
+        len
+    ))
+}
+
+/// Generate `size` numbers, the first invalid of which is at a random position after the preamble.
+///
+/// The invalid number is the sum of the shortest run of numbers right before it that exceeds
+/// every number so far, as well as any sum of two of the numbers in its window. Only the numbers
+/// up to it have to be valid, so those after it are just random ones below it, which keeps any
+/// size from overflowing.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, PREAMBLE + 1)?;
+    let mut numbers: Vec<i64> = vec![];
+    while numbers.len() < PREAMBLE {
+        let number = rng.between(1, 100) as i64;
+        if !numbers.contains(&number) {
+            numbers.push(number);
+        }
+    }
+    let invalid_at = rng.between(PREAMBLE as u64, (size - 1).min(MAX_VALID) as u64) as usize;
+    while numbers.len() < invalid_at {
+        let number = valid_number(rng, &numbers[numbers.len() - PREAMBLE..], numbers.len())?;
+        numbers.push(number);
+    }
+
+    let window = &numbers[invalid_at - PREAMBLE..];
+    let bound = window
+        .iter()
+        .max()
+        .unwrap()
+        .checked_mul(2)
+        .ok_or_else(|| overflow(invalid_at))?
+        .max(*numbers.iter().max().unwrap());
+    let mut invalid = 0i64;
+    for number in numbers.iter().rev() {
+        invalid = invalid
+            .checked_add(*number)
+            .ok_or_else(|| overflow(invalid_at))?;
+        if invalid > bound {
+            break;
+        }
+    }
+    numbers.push(invalid);
+    while numbers.len() < size {
+        numbers.push(rng.between(1, invalid as u64) as i64);
+    }
+
+    // The contiguous set that ends first; since the invalid number is larger than any before it,
+    // the set has at least two numbers.
+    let mut set = &numbers[..0];
+    'end: for end in 1..invalid_at {
+        let mut sum = 0;
+        for start in (0..=end).rev() {
+            sum += numbers[start];
+            if sum == invalid {
+                set = &numbers[start..=end];
+                break 'end;
+            } else if sum > invalid {
+                break;
+            }
+        }
+    }
+    let weakness = set.iter().min().unwrap() + set.iter().max().unwrap();
+
+    let input: String = numbers.iter().map(|x| format!("{}\n", x)).collect();
+    Ok(Generated::new(input)
+        .with_answer(Part::One, invalid)
+        .with_answer(Part::Two, weakness))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day09::{part1, part2};
+
+    /// Far more numbers than fit before the invalid one without overflowing.
+    #[test]
+    fn any_size() {
+        for seed in 0..8 {
+            let generated = generate(&mut Rng::new(seed), 20_000).unwrap();
+            let numbers: Vec<i64> = generated
+                .input
+                .lines()
+                .map(|line| line.parse().unwrap())
+                .collect();
+            assert_eq!(numbers.len(), 20_000);
+            let invalid = part1::find_invalid(&numbers, PREAMBLE).unwrap();
+            let set = &numbers[part2::subslice_sum(&numbers, invalid).unwrap()];
+            let weakness = set.iter().min().unwrap() + set.iter().max().unwrap();
+            assert_eq!(
+                generated.answers,
+                [
+                    (Part::One, invalid.to_string()),
+                    (Part::Two, weakness.to_string())
+                ],
+                "seed {}",
+                seed
+            );
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    details, read_parsed_with, Details, Explanation, Generated, Malformed, Result, Rng, Solution,
+};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+
+/// The length of the preamble by default, which the generated inputs are built for.
+const PREAMBLE: usize = 25;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day09 {
+    /// The number of preceding numbers that each number must be a 2SUM solution of.
+    pub preamble: usize,
+}
+
+impl Day09 {
+    pub const DEFAULT: Day09 = Day09 { preamble: PREAMBLE };
+}
+
+impl Solution for Day09 {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day09/input.txt"
+    }
+
+    /// Parse a number per line, none of which may be negative: the search for the contiguous set
+    /// relies on its sum only ever growing as it takes in more numbers.
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        read_parsed_with(path, |line| match line.parse::<i64>()? {
+            number if number < 0 => Err(Malformed::new("expected a non-negative number")),
+            number => Ok(number),
+        })
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Result<Self::Answer1> {
+        part1::find_invalid(numbers, self.preamble)
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Result<Self::Answer2> {
+        let range = part2::subslice_sum(numbers, part1::find_invalid(numbers, self.preamble)?)?;
+        let subset = &numbers[range];
+        Ok(subset.iter().min().unwrap() + subset.iter().max().unwrap())
+    }
+
+    fn details1(&self, _: &Self::Input, _: &Self::Answer1) -> Result<Details> {
+        Ok(details(vec![("preamble", self.preamble)]))
+    }
+
+    /// The invalid number, and the smallest and largest of the contiguous set adding up to it.
+    fn details2(&self, numbers: &Self::Input, _: &Self::Answer2) -> Result<Details> {
+        let invalid = part1::find_invalid(numbers, self.preamble)?;
+        let subset = &numbers[part2::subslice_sum(numbers, invalid)?];
+        Ok(details(vec![
+            ("invalid", invalid),
+            ("smallest", *subset.iter().min().unwrap()),
+            ("largest", *subset.iter().max().unwrap()),
+        ]))
+    }
+
+    /// The contiguous range of numbers adding up to the invalid one.
+    fn explain2(&self, numbers: &Self::Input, weakness: &Self::Answer2) -> Result<Explanation> {
+        let invalid = part1::find_invalid(numbers, self.preamble)?;
+        let range = part2::subslice_sum(numbers, invalid)?;
+        let subset = &numbers[range.clone()];
+        let terms: Vec<_> = subset.iter().map(i64::to_string).collect();
+        let (smallest, largest) = (subset.iter().min().unwrap(), subset.iter().max().unwrap());
+        Ok(vec![
+            format!(
+                "{} is not the sum of two of the {} before it",
+                invalid, self.preamble
+            ),
+            format!(
+                "lines {} to {}: {} = {}",
+                range.start + 1,
+                range.end,
+                terms.join(" + "),
+                invalid
+            ),
+            format!("{} + {} = {}", smallest, largest, weakness),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+    use crate::Error;
+
+    /// The example has no sidecar, since its preamble is only 5 numbers long.
+    #[test]
+    fn example() {
+        let day = Day09 { preamble: 5 };
+        let numbers = day.parse(Path::new("day09/example.txt")).unwrap();
+        assert_eq!(day.part1(&numbers).unwrap(), 127);
+        assert_eq!(day.part2(&numbers).unwrap(), 62);
+    }
+
+    #[test]
+    fn reject_negatives() {
+        let dir = TempDir::new("day09");
+        let path = dir.join("input.txt");
+        std::fs::write(&path, "1\n2\n-3\n4\n").unwrap();
+        match Day09::DEFAULT.parse(&path) {
+            Err(Error::Parse { line, .. }) => assert_eq!(line, 3),
+            res => panic!("unexpected result {:?}", res),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use crate::{Error, Result};
+
+/// Find a solution for 2SUM in the given `numbers` slice for the given `target`, i.e. two numbers
+/// of different values that sum to it.
+/// If no such solution exists, `target` is returned wrapped in an `Err`.
+///
+/// O(n) amortized
+fn two_sum(numbers: &[i64], target: i64, h: &mut HashSet<i64>) -> Result<(), i64> {
+    h.clear();
+    for num in numbers {
+        h.insert(*num);
+    }
+    for num in numbers {
+        if target - num != *num && h.contains(&(target - num)) {
+            return Ok(());
+        }
+    }
+    Err(target)
+}
+
+/// Find the first number in the given `numbers` slice (after the given `preamble`) which is not a
+/// 2SUM solution of its previous `preamble` numbers.
+///
+/// Returns an error if such a number does not exist.
+///
+/// O((N-P)*P) ~ O(N) for P<<N
+pub fn find_invalid(numbers: &[i64], preamble: usize) -> Result<i64> {
+    if numbers.len() <= preamble {
+        return Err(Error::InvalidInput(format!(
+            "{} numbers do not suffice for a preamble of {}",
+            numbers.len(),
+            preamble
+        )));
+    }
+    let mut h = HashSet::with_capacity(preamble);
+    for (i, num) in numbers[preamble..].iter().enumerate() {
+        if let Err(result) = two_sum(&numbers[i..i + preamble], *num, &mut h) {
+            return Ok(result);
+        }
+    }
+    Err(Error::NoSolution(
+        "every number is the sum of two of its preceding ones".to_owned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    /// Reference: check every pair of different numbers in the window of every number, in
+    /// O(n*p^2).
+    fn find_invalid_naive(numbers: &[i64], preamble: usize) -> Option<i64> {
+        (preamble..numbers.len())
+            .find(|&i| {
+                let window = &numbers[i - preamble..i];
+                !window.iter().enumerate().any(|(j, a)| {
+                    window[j + 1..]
+                        .iter()
+                        .any(|b| a != b && a + b == numbers[i])
+                })
+            })
+            .map(|i| numbers[i])
+    }
+
+    #[test]
+    fn same_as_naive() {
+        let mut rng = Rng::new(9);
+        for _ in 0..2000 {
+            let preamble = rng.between(2, 5) as usize;
+            let len = rng.between(0, 15) as usize;
+            let numbers: Vec<_> = (0..len).map(|_| rng.between(0, 10) as i64).collect();
+            let expected = find_invalid_naive(&numbers, preamble);
+            match find_invalid(&numbers, preamble) {
+                Ok(invalid) => assert_eq!(Some(invalid), expected, "{:?}", numbers),
+                Err(_) => assert_eq!(expected, None, "{:?}", numbers),
+            }
+        }
+    }
+}
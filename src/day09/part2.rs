@@ -0,0 +1,83 @@
+use std::ops::Range;
+
+use crate::{Error, Result};
+
+/// Find the range of the first subslice of at least two numbers within the given (non-negative)
+/// `numbers` slice which numbers sum to the given `target`.
+///
+/// Returns an error if such a subslice does not exist.
+///
+/// O(n)
+pub fn subslice_sum(numbers: &[i64], target: i64) -> Result<Range<usize>> {
+    if numbers.len() < 2 {
+        return Err(Error::InvalidInput(format!(
+            "{} numbers do not suffice for a contiguous set of at least two",
+            numbers.len()
+        )));
+    }
+    let (mut l, mut r) = (0, 1);
+    let mut running_sum = numbers[l] + numbers[r];
+    loop {
+        if running_sum == target {
+            return Ok(l..r + 1);
+        }
+        if running_sum < target || r - l == 1 {
+            // A set of two cannot shrink, so it has to grow even if its sum is too large.
+            r += 1;
+            if r == numbers.len() {
+                break; // the running sum cannot grow any further
+            }
+            running_sum += numbers[r];
+        } else {
+            running_sum -= numbers[l];
+            l += 1;
+        }
+    }
+    Err(Error::NoSolution(format!(
+        "no contiguous set of numbers sums to {}",
+        target
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    /// Reference: sum every subslice of at least two numbers, in O(n^2), and return the range of
+    /// the one that ends first (and is the shortest among those that end there).
+    fn subslice_sum_naive(numbers: &[i64], target: i64) -> Option<Range<usize>> {
+        (1..numbers.len()).find_map(|end| {
+            let mut sum = numbers[end];
+            (0..end).rev().find_map(|start| {
+                sum += numbers[start];
+                (sum == target).then_some(start..end + 1)
+            })
+        })
+    }
+
+    #[test]
+    fn same_as_naive() {
+        let mut rng = Rng::new(10);
+        for _ in 0..5000 {
+            let len = rng.between(0, 12) as usize;
+            let low = rng.between(0, 1);
+            let numbers: Vec<_> = (0..len).map(|_| rng.between(low, 9) as i64).collect();
+            let target = rng.between(0, 40) as i64;
+            let expected = subslice_sum_naive(&numbers, target);
+            match subslice_sum(&numbers, target) {
+                Ok(found) => {
+                    assert!(expected.is_some(), "{:?} has no set to {}", numbers, target);
+                    assert!(found.len() >= 2, "{:?}: {:?}", numbers, found);
+                    let sum: i64 = numbers[found.clone()].iter().sum();
+                    assert_eq!(sum, target, "{:?}", numbers);
+                    // Without zeros, only one set may end at any number.
+                    if low > 0 {
+                        assert_eq!(Some(found), expected, "{:?} to {}", numbers, target);
+                    }
+                }
+                Err(_) => assert_eq!(expected, None, "{:?} has a set to {}", numbers, target),
+            }
+        }
+    }
+}
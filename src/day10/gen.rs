@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use crate::gen::check_size;
+use crate::{Generated, Part, Result, Rng};
+
+/// Generate the ratings of `size` adapters, in random order, which differ by 1 to 3 jolts from
+/// the next larger one (mostly by 1 or 3, as in the real inputs).
+///
+/// The number of arrangements grows exponentially with the number of adapters, so its answer is
+/// only known as long as it fits in a `u64`.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 1)?;
+    let mut ratings = Vec::with_capacity(size);
+    let (mut rating, mut ones, mut threes) = (0u64, 0u64, 1u64);
+    for _ in 0..size {
+        let difference = match rng.index(20) {
+            0 => 2,
+            1..=7 => 3,
+            _ => 1,
+        };
+        match difference {
+            1 => ones += 1,
+            3 => threes += 1,
+            _ => (),
+        }
+        rating += difference;
+        ratings.push(rating);
+    }
+
+    let mut arrangements = HashMap::with_capacity(size + 1);
+    arrangements.insert(0, Some(1u64));
+    for &rating in &ratings {
+        let count = (1..=rating.min(3))
+            .filter_map(|difference| arrangements.get(&(rating - difference)).copied())
+            .try_fold(0u64, |sum, count| sum.checked_add(count?));
+        arrangements.insert(rating, count);
+    }
+
+    rng.shuffle(&mut ratings);
+    let input: String = ratings.iter().map(|x| format!("{}\n", x)).collect();
+    let generated = Generated::new(input).with_answer(Part::One, ones * threes);
+    Ok(match arrangements[&rating] {
+        Some(count) => generated.with_answer(Part::Two, count),
+        None => generated,
+    })
+}
@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{read_parsed, Generated, Result, Rng, Solution};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day10 {
+    /// How many jolts lower than its rating the input of an adapter may be.
+    pub max_jump: u64,
+}
+
+impl Day10 {
+    pub const DEFAULT: Day10 = Day10 { max_jump: 3 };
+}
+
+impl Solution for Day10 {
+    /// The joltage ratings of the adapters, sorted in ascending order.
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day10/input.txt"
+    }
+
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        let mut ratings: Vec<u64> = read_parsed(path)?;
+        ratings.sort_unstable();
+        Ok(ratings)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, ratings: &Self::Input) -> Result<Self::Answer1> {
+        part1::jolt_differences(ratings, self.max_jump)
+    }
+
+    fn part2(&self, ratings: &Self::Input) -> Result<Self::Answer2> {
+        part2::count_arrangements(ratings, self.max_jump)
+    }
+}
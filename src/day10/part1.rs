@@ -0,0 +1,53 @@
+use crate::{Error, Result};
+
+/// Return the number of 1-jolt differences multiplied by the number of `max_jump`-jolt differences
+/// in the chain that uses all (sorted) adapter `ratings`, whose device is rated `max_jump` jolts
+/// higher than the highest adapter.
+///
+/// Returns an error if two adapters in a row (or the outlet and the first one) are more than
+/// `max_jump` jolts apart, since then there is no such chain.
+pub fn jolt_differences(ratings: &[u64], max_jump: u64) -> Result<u64> {
+    let first = ratings
+        .first()
+        .ok_or_else(|| Error::InvalidInput("no adapters".to_owned()))?;
+    let mut differences = Vec::with_capacity(ratings.len() + 1);
+    differences.push(*first);
+    for (i, _) in ratings.iter().enumerate().skip(1) {
+        differences.push(ratings[i] - ratings[i - 1]);
+    }
+    differences.push(max_jump);
+
+    let (mut j1, mut jmax) = (0, 0);
+    for diff in differences {
+        if diff > max_jump {
+            return Err(Error::NoSolution(format!(
+                "the adapters cannot all be chained: {} jolts apart is more than {}",
+                diff, max_jump
+            )));
+        }
+        if 1 == diff {
+            j1 += 1;
+        }
+        if max_jump == diff {
+            jmax += 1;
+        }
+    }
+
+    Ok(j1 * jmax)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_jump() {
+        let ratings = [1, 2, 4, 7];
+        assert_eq!(jolt_differences(&ratings, 3).unwrap(), 2 * 2);
+        assert_eq!(jolt_differences(&ratings, 4).unwrap(), 2);
+        assert!(matches!(
+            jolt_differences(&ratings, 2),
+            Err(Error::NoSolution(_))
+        ));
+    }
+}
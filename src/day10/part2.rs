@@ -0,0 +1,102 @@
+use tracing::trace;
+
+use crate::{Error, Result};
+
+/// Count the distinct ways in which the (sorted) adapter `ratings` can be arranged to connect the
+/// charging outlet to the device, if each adapter takes an input at most `max_jump` jolts lower.
+///
+/// An error is returned if there are more arrangements than fit in a `u64`.
+pub fn count_arrangements(ratings: &[u64], max_jump: u64) -> Result<u64> {
+    // The arrangements up to each rating, or `None` if there are too many of them to count.
+    let mut memo = std::collections::HashMap::with_capacity(1 + ratings.len());
+    memo.insert(0, Some(1u64));
+    ratings.iter().for_each(|&r| {
+        // NOTE: If r < max_jump then ∀i∈[1, r], otherwise ∀i∈[1, max_jump]
+        let count = (1..=max_jump.min(r))
+            .filter_map(|i| memo.get(&(r - i)).copied())
+            .try_fold(0u64, |sum, count| sum.checked_add(count?));
+        // Adapters of the same rating are alternatives to each other, so their counts add up.
+        let total = memo.entry(r).or_insert(Some(0));
+        *total = total
+            .zip(count)
+            .and_then(|(total, count)| total.checked_add(count));
+        trace!(rating = r, arrangements = ?memo[&r], "adapter");
+    });
+
+    let last = ratings
+        .last()
+        .ok_or_else(|| Error::InvalidInput("no adapters".to_owned()))?;
+    memo[last].ok_or_else(|| {
+        Error::NoSolution("there are more arrangements than fit in 64 bits".to_owned())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    /// Reference: try every subset of the adapters, in O(2^n * n).
+    fn count_arrangements_naive(ratings: &[u64]) -> Option<u64> {
+        let last = *ratings.last()?;
+        // Each adapter must be rated 1 to 3 jolts above the previous one (or the outlet), and
+        // the last one must be the largest, for the device to connect to it.
+        let count = (0..1u32 << ratings.len())
+            .filter(|subset| {
+                let mut prev = 0;
+                for (i, &r) in ratings.iter().enumerate() {
+                    if subset >> i & 1 == 1 {
+                        if r <= prev || r - prev > 3 {
+                            return false;
+                        }
+                        prev = r;
+                    }
+                }
+                prev == last
+            })
+            .count();
+        Some(count as u64)
+    }
+
+    #[test]
+    fn overflow() {
+        // Each adapter 1 jolt above the previous one: the counts grow like the tribonacci numbers,
+        // which exceed `u64::MAX` from the 74th adapter on.
+        let ratings: Vec<u64> = (1..=100).collect();
+        assert!(count_arrangements(&ratings[..70], 3).is_ok());
+        assert!(matches!(
+            count_arrangements(&ratings, 3),
+            Err(Error::NoSolution(_))
+        ));
+
+        let mut rng = Rng::new(12);
+        for _ in 0..200 {
+            let len = rng.between(1, 140) as usize;
+            let ratings: Vec<u64> = (1..=len as u64).collect();
+            // Reference: the same recurrence in 128 bits, which only overflows from the 147th on.
+            let mut counts = vec![1u128];
+            for i in 1..=len {
+                counts.push(counts[i.saturating_sub(3)..i].iter().sum());
+            }
+            match count_arrangements(&ratings, 3) {
+                Ok(count) => assert_eq!(count as u128, counts[len]),
+                Err(_) => assert!(counts[len] > u64::MAX as u128, "{}", len),
+            }
+        }
+    }
+
+    #[test]
+    fn same_as_naive() {
+        let mut rng = Rng::new(11);
+        for _ in 0..1000 {
+            let len = rng.between(0, 12) as usize;
+            let mut ratings: Vec<_> = (0..len).map(|_| rng.between(0, 20)).collect();
+            ratings.sort_unstable();
+            let expected = count_arrangements_naive(&ratings);
+            match count_arrangements(&ratings, 3) {
+                Ok(count) => assert_eq!(Some(count), expected, "{:?}", ratings),
+                Err(_) => assert_eq!(expected, None, "{:?}", ratings),
+            }
+        }
+    }
+}
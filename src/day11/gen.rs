@@ -0,0 +1,18 @@
+use crate::gen::check_size;
+use crate::{Generated, Result, Rng};
+
+/// Generate a square layout of `size` rows of `size` seats each, about three quarters of which
+/// are empty seats and the rest floor.
+///
+/// No answers are known: they take simulating the seating rules, which is what the solver does.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 1)?;
+    let mut input = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(if rng.chance(0.75) { 'L' } else { '.' });
+        }
+        input.push('\n');
+    }
+    Ok(Generated::new(input))
+}
@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, trace};
+
+use crate::grid::{Grid, Pos};
+use crate::{Error, Explanation, Generated, Malformed, Result, Rng, Solution, Visualize};
+
+mod gen;
+pub mod part1;
+pub mod part2;
+mod render;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Seat {
+    Empty,
+    Occupied,
+    Floor,
+}
+
+impl std::convert::TryFrom<char> for Seat {
+    type Error = Malformed;
+
+    fn try_from(availability: char) -> Result<Self, Self::Error> {
+        match availability {
+            'L' => Ok(Seat::Empty),
+            '#' => Ok(Seat::Occupied),
+            '.' => Ok(Seat::Floor),
+            s => Err(Malformed::new(format!("invalid seat {:?}", s))),
+        }
+    }
+}
+
+impl std::fmt::Display for Seat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Seat::Empty => write!(f, "L"),
+            Seat::Occupied => write!(f, "#"),
+            Seat::Floor => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Layout(Grid<Seat>);
+
+impl std::fmt::Display for Layout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Layout {
+    /// Apply the seating rules once: an empty seat becomes occupied if there are no occupied seats
+    /// around it, while an occupied seat is emptied if at least `crowdy` seats around it are
+    /// occupied; `order` counts the occupied seats around a position.
+    fn step(&self, crowdy: usize, order: impl Fn(Pos) -> usize) -> Self {
+        const EMPTY: usize = 0;
+
+        let mut next = self.clone();
+        for pos in self.0.positions() {
+            match self.0[pos] {
+                Seat::Empty if order(pos) == EMPTY => next.0[pos] = Seat::Occupied,
+                Seat::Occupied if order(pos) >= crowdy => next.0[pos] = Seat::Empty,
+                _ => (), // skip floor and seats that do not change
+            }
+        }
+        next
+    }
+
+    fn count_seats(&self) -> usize {
+        self.0
+            .cells()
+            .iter()
+            .filter(|&&seat| seat != Seat::Floor)
+            .count()
+    }
+
+    fn count_occupied(&self) -> usize {
+        self.0
+            .cells()
+            .iter()
+            .filter(|&&seat| seat == Seat::Occupied)
+            .count()
+    }
+}
+
+/// Keep stepping the `layout` until it no longer changes and return the number of occupied seats,
+/// along with the number of steps that changed it.
+fn stabilize(layout: Layout, step: impl Fn(&Layout) -> Layout) -> Result<(usize, usize)> {
+    stabilize_with(layout, step, |_| ())
+}
+
+/// Like [`stabilize`], but also `visit` each layout along the way, the initial one included.
+///
+/// An error is returned if the layout comes back to an earlier one instead of settling, as it then
+/// keeps changing forever.
+fn stabilize_with(
+    mut layout: Layout,
+    step: impl Fn(&Layout) -> Layout,
+    mut visit: impl FnMut(&Layout),
+) -> Result<(usize, usize)> {
+    trace!("initial layout:\n{}", layout);
+    visit(&layout);
+    let mut seen = HashSet::new();
+    for steps in 0.. {
+        let next = step(&layout);
+        debug!(step = steps + 1, occupied = next.count_occupied(), "step");
+        trace!("next layout:\n{}", next);
+        if next == layout {
+            return Ok((layout.count_occupied(), steps));
+        }
+        if seen.contains(&next) {
+            return Err(Error::NoSolution(format!(
+                "the layout never settles: step {} repeats an earlier one",
+                steps + 1
+            )));
+        }
+        visit(&next);
+        seen.insert(std::mem::replace(&mut layout, next));
+    }
+    unreachable!()
+}
+
+/// How many steps it took the seats to stabilize, and how many of them ended up occupied.
+fn explain_stabilize(steps: usize, occupied: usize, total: usize) -> Explanation {
+    vec![
+        format!("the layout stops changing after {} steps", steps),
+        format!("{} of {} seats are then occupied", occupied, total),
+    ]
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Day11 {
+    /// How many of the adjacent seats must be occupied for someone to leave theirs, in part 1.
+    pub crowdy_adjacent: usize,
+    /// How many of the visible seats must be occupied for someone to leave theirs, in part 2.
+    pub crowdy_visible: usize,
+}
+
+impl Day11 {
+    pub const DEFAULT: Day11 = Day11 {
+        crowdy_adjacent: 4,
+        crowdy_visible: 5,
+    };
+}
+
+impl Solution for Day11 {
+    type Input = Layout;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day11/input.txt"
+    }
+
+    /// Read the layout of the seats; all rows must be of the same length.
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        Grid::read(path).map(Layout)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, layout: &Self::Input) -> Result<Self::Answer1> {
+        let (occupied, _) = stabilize(layout.clone(), |l| l.step_adjacent(self.crowdy_adjacent))?;
+        Ok(occupied)
+    }
+
+    fn part2(&self, layout: &Self::Input) -> Result<Self::Answer2> {
+        let (occupied, _) = stabilize(layout.clone(), |l| l.step_visible(self.crowdy_visible))?;
+        Ok(occupied)
+    }
+
+    fn visualization(&self) -> Option<&dyn Visualize<Input = Self::Input>> {
+        Some(self)
+    }
+
+    fn explain1(&self, layout: &Self::Input, _: &Self::Answer1) -> Result<Explanation> {
+        let (occupied, steps) =
+            stabilize(layout.clone(), |l| l.step_adjacent(self.crowdy_adjacent))?;
+        Ok(explain_stabilize(steps, occupied, layout.count_seats()))
+    }
+
+    fn explain2(&self, layout: &Self::Input, _: &Self::Answer2) -> Result<Explanation> {
+        let (occupied, steps) = stabilize(layout.clone(), |l| l.step_visible(self.crowdy_visible))?;
+        Ok(explain_stabilize(steps, occupied, layout.count_seats()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Part, Visualize};
+
+    #[test]
+    fn never_settle() -> Result<()> {
+        // Every empty seat fills up, and then every occupied seat is left, over and over again.
+        let restless = Day11 {
+            crowdy_adjacent: 0,
+            ..Day11::DEFAULT
+        };
+        let layout = restless.parse(Path::new("day11/example.txt"))?;
+        assert!(matches!(restless.part1(&layout), Err(Error::NoSolution(_))));
+        assert!(matches!(
+            restless.frames(&layout, Part::One),
+            Err(Error::NoSolution(_))
+        ));
+        assert_eq!(Day11::DEFAULT.part1(&layout)?, 37);
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use super::{Layout, Seat};
+
+impl Layout {
+    /// Apply the seating rules once, considering the (up to) eight seats adjacent to each seat.
+    pub fn step_adjacent(&self, crowdy: usize) -> Self {
+        // TODO: Non-optimal: the order of each Seat will be calculated once per adjacent ~ O(A*N).
+        self.step(crowdy, |pos| {
+            self.0
+                .neighbors(pos)
+                .filter(|&adjacent| self.0[adjacent] == Seat::Occupied)
+                .count()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,25 @@
+use super::{Layout, Seat};
+use crate::geom::Heading;
+
+impl Layout {
+    /// Apply the seating rules once, considering the first seat visible in each of the eight
+    /// directions from each seat.
+    pub fn step_visible(&self, crowdy: usize) -> Self {
+        // TODO: Non-optimal: the order of each Seat will be calculated multiple times.
+        self.step(crowdy, |pos| {
+            Heading::ALL
+                .iter()
+                .filter(|&&heading| {
+                    self.0
+                        .ray(pos, heading)
+                        .map(|visible| self.0[visible])
+                        .find(|&seat| seat != Seat::Floor)
+                        == Some(Seat::Occupied)
+                })
+                .count()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {}
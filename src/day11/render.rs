@@ -0,0 +1,34 @@
+use super::{stabilize_with, Day11, Layout, Seat};
+use crate::render::{Frame, Rgb, Visualize};
+use crate::{Part, Result};
+
+const EMPTY: Rgb = [0xb0, 0xbe, 0xc5];
+const OCCUPIED: Rgb = [0xc6, 0x28, 0x28];
+
+/// Every generation of the seats, from the initial layout up to the one that no longer changes.
+impl Visualize for Day11 {
+    type Input = Layout;
+
+    fn frames(&self, layout: &Self::Input, part: Part) -> Result<Vec<Frame>> {
+        let step = |layout: &Layout| match part {
+            Part::One => layout.step_adjacent(self.crowdy_adjacent),
+            Part::Two => layout.step_visible(self.crowdy_visible),
+        };
+        let mut frames = vec![];
+        stabilize_with(layout.clone(), step, |layout| frames.push(draw(layout)))?;
+        Ok(frames)
+    }
+}
+
+fn draw(layout: &Layout) -> Frame {
+    let mut frame = Frame::new();
+    for (row, column) in layout.0.positions() {
+        let color = match layout.0[(row, column)] {
+            Seat::Empty => EMPTY,
+            Seat::Occupied => OCCUPIED,
+            Seat::Floor => continue,
+        };
+        frame.cell((column as i64, row as i64), color);
+    }
+    frame
+}
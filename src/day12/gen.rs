@@ -0,0 +1,33 @@
+use crate::gen::check_size;
+use crate::{Generated, Part, Result, Rng};
+
+/// Generate `size` navigation instructions: moves of up to 100 units in one of the directions or
+/// forward, and turns of 90, 180 or 270 degrees.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated> {
+    check_size(size, 1)?;
+    let mut input = String::new();
+    // The position as (east, north), and the heading as a number of right turns from north.
+    let (mut east, mut north, mut heading) = (0i64, 0i64, 1);
+    for _ in 0..size {
+        let action = *rng.choose(&['N', 'S', 'E', 'W', 'L', 'R', 'F', 'F']);
+        let value = match action {
+            'L' | 'R' => 90 * rng.between(1, 3) as i64,
+            _ => rng.between(1, 100) as i64,
+        };
+        let mut travel = |action, value| match action {
+            'N' => north += value,
+            'S' => north -= value,
+            'E' => east += value,
+            'W' => east -= value,
+            _ => unreachable!(),
+        };
+        match action {
+            'L' => heading = (heading + 4 - value / 90) % 4,
+            'R' => heading = (heading + value / 90) % 4,
+            'F' => travel(['N', 'E', 'S', 'W'][heading as usize], value),
+            _ => travel(action, value),
+        }
+        input.push_str(&format!("{}{}\n", action, value));
+    }
+    Ok(Generated::new(input).with_answer(Part::One, east.abs() + north.abs()))
+}
@@ -0,0 +1,104 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::geom::{Heading, Point};
+use crate::{
+    details, read_parsed, Details, Error, Generated, Malformed, Part, Result, Rng, Solution,
+    Visualize,
+};
+
+mod gen;
+pub mod part1;
+mod render;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    /// Move by the given number of units in one of the cardinal headings.
+    Move(Heading, i64),
+    /// Turn left by the given number of degrees, a multiple of 90.
+    Left(i64),
+    /// Turn right by the given number of degrees, a multiple of 90.
+    Right(i64),
+    Forward(i64),
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = Malformed;
+
+    /// Parse an instruction of the form `<action><value>`, e.g. `F10`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let action = chars
+            .next()
+            .ok_or_else(|| Malformed::new("expected `<action><value>`"))?;
+        let offset = action.len_utf8();
+        let by = chars
+            .as_str()
+            .parse::<i64>()
+            .map_err(|err| Malformed::from(err).at(offset))?;
+        if matches!(action, 'L' | 'R') && by % 90 != 0 {
+            return Err(Malformed::new("expected a multiple of 90 degrees").at(offset));
+        }
+        match action {
+            'N' => Ok(Instruction::Move(Heading::North, by)),
+            'S' => Ok(Instruction::Move(Heading::South, by)),
+            'E' => Ok(Instruction::Move(Heading::East, by)),
+            'W' => Ok(Instruction::Move(Heading::West, by)),
+            'L' => Ok(Instruction::Left(by)),
+            'R' => Ok(Instruction::Right(by)),
+            'F' => Ok(Instruction::Forward(by)),
+            _ => Err(Malformed::new(format!("unknown action {:?}", action))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Instruction>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn default_input(&self) -> &'static str {
+        "day12/input.txt"
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        &[Part::One]
+    }
+
+    fn parse(&self, path: &Path) -> Result<Self::Input> {
+        read_parsed(path)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Result<Self::Answer1> {
+        let ferry = part1::navigate(instructions);
+        Ok(ferry.pos.manhattan(Point::ORIGIN))
+    }
+
+    fn visualization(&self) -> Option<&dyn Visualize<Input = Self::Input>> {
+        Some(self)
+    }
+
+    /// The final position of the ferry, relative to where it started.
+    fn details1(&self, instructions: &Self::Input, _: &Self::Answer1) -> Result<Details> {
+        let ferry = part1::navigate(instructions);
+        Ok(details(vec![("north", ferry.pos.y), ("east", ferry.pos.x)]))
+    }
+
+    fn part2(&self, _: &Self::Input) -> Result<Self::Answer2> {
+        Err(Error::NoSolution(
+            "part 2 has not been solved yet".to_owned(),
+        ))
+    }
+}
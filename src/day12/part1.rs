@@ -0,0 +1,38 @@
+use super::Instruction;
+use crate::geom::{Heading, Point};
+
+#[derive(Debug)]
+pub struct Ferry {
+    pub pos: Point,
+    heading: Heading,
+}
+
+impl Ferry {
+    pub fn new(heading: Heading) -> Self {
+        Ferry {
+            pos: Point::ORIGIN,
+            heading,
+        }
+    }
+
+    pub fn follow(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Move(heading, by) => self.pos += heading.vector() * by,
+            Instruction::Left(degrees) => self.heading = self.heading.turn_left(degrees / 90),
+            Instruction::Right(degrees) => self.heading = self.heading.turn_right(degrees / 90),
+            Instruction::Forward(by) => self.pos += self.heading.vector() * by,
+        };
+    }
+}
+
+/// Follow all `instructions`, starting off facing east.
+pub fn navigate(instructions: &[Instruction]) -> Ferry {
+    let mut ferry = Ferry::new(Heading::East);
+    instructions.iter().for_each(|&instruction| {
+        ferry.follow(instruction);
+    });
+    ferry
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,51 @@
+use super::part1::Ferry;
+use super::{Day12, Instruction};
+use crate::geom::{Heading, Point};
+use crate::render::{Frame, Rgb, Visualize};
+use crate::{Error, Part, Result};
+
+const TRACK: Rgb = [0x15, 0x65, 0xc0];
+const START: Rgb = [0x2e, 0x7d, 0x32];
+const FERRY: Rgb = [0xd3, 0x2f, 0x2f];
+
+/// The most frames of an animation; longer tracks are drawn a few instructions at a time.
+const MAX_FRAMES: usize = 100;
+
+/// The track of the ferry growing as it follows the instructions, with north up.
+impl Visualize for Day12 {
+    type Input = Vec<Instruction>;
+
+    fn frames(&self, instructions: &Self::Input, part: Part) -> Result<Vec<Frame>> {
+        if part == Part::Two {
+            return Err(Error::NoSolution(
+                "part 2 has not been solved yet".to_owned(),
+            ));
+        }
+        let mut ferry = Ferry::new(Heading::East);
+        let mut track = vec![cell(ferry.pos)];
+        for &instruction in instructions {
+            ferry.follow(instruction);
+            track.push(cell(ferry.pos));
+        }
+
+        let per_frame = instructions.len().div_ceil(MAX_FRAMES).max(1);
+        let mut frames = vec![];
+        let mut end = 0;
+        loop {
+            end = (end + per_frame).min(track.len() - 1);
+            let mut frame = Frame::new();
+            frame.path(track[..=end].to_vec(), TRACK);
+            frame.cell(track[0], START);
+            frame.cell(track[end], FERRY);
+            frames.push(frame);
+            if end == track.len() - 1 {
+                return Ok(frames);
+            }
+        }
+    }
+}
+
+/// The cell that a point falls on, with `y` flipped so that north is up.
+fn cell(point: Point) -> (i64, i64) {
+    (point.x, -point.y)
+}
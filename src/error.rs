@@ -0,0 +1,215 @@
+use std::fmt::{self, Display};
+use std::io;
+
+/// A specialized `Result` type for all puzzle solutions.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The errors that may occur while reading, parsing or solving a puzzle's input.
+#[derive(Debug)]
+pub enum Error {
+    /// The input could not be read.
+    Io(io::Error),
+    /// A line of the input is malformed.
+    Parse {
+        /// The name of the input (usually its path).
+        input: String,
+        /// The 1-based number of the offending line.
+        line: usize,
+        /// The 1-based (character) column that the problem was found at.
+        column: usize,
+        /// The contents of the offending line.
+        snippet: String,
+        /// What is wrong with the line.
+        reason: String,
+    },
+    /// The input is well-formed, but the puzzle has no solution for it.
+    NoSolution(String),
+    /// The input is well-formed, but violates some assumption of the puzzle.
+    InvalidInput(String),
+    /// A request to the puzzle server failed.
+    Http(String),
+}
+
+impl Error {
+    /// Render the error as a diagnostic which, for parse errors, points at the offending part of
+    /// the input line like a compiler error does; e.g.:
+    ///
+    /// ```text
+    /// error: invalid digit found in string
+    ///  --> day01/input.txt:3:1
+    ///   |
+    /// 3 | x3
+    ///   | ^^
+    /// ```
+    pub fn render(&self) -> String {
+        match self {
+            Error::Parse {
+                input,
+                line,
+                column,
+                snippet,
+                reason,
+            } => {
+                let gutter = " ".repeat(line.to_string().len());
+                // Underline the token that the column points into, i.e. up to the next whitespace.
+                let width = snippet
+                    .chars()
+                    .skip(column - 1)
+                    .take_while(|c| !c.is_whitespace())
+                    .count()
+                    .max(1);
+                format!(
+                    "error: {reason}\n{gutter}--> {input}:{line}:{column}\n{gutter} |\n{line} | {snippet}\n{gutter} | {pad}{carets}",
+                    reason = reason,
+                    gutter = gutter,
+                    input = input,
+                    line = line,
+                    column = column,
+                    snippet = snippet,
+                    pad = " ".repeat(column - 1),
+                    carets = "^".repeat(width),
+                )
+            }
+            err => format!("error: {}", err),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Parse {
+                input,
+                line,
+                column,
+                reason,
+                ..
+            } => write!(f, "{}:{}:{}: {}", input, line, column, reason),
+            Error::NoSolution(reason) => write!(f, "no solution: {}", reason),
+            Error::InvalidInput(reason) => write!(f, "invalid input: {}", reason),
+            Error::Http(reason) => write!(f, "request failed: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// The reason a single record of the input could not be parsed, along with where in the record
+/// the problem lies. It becomes an [`Error::Parse`] once the record is attributed to its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Malformed {
+    /// The 0-based index of the offending line within the record.
+    pub line: usize,
+    /// The 0-based byte offset within the offending line.
+    pub offset: usize,
+    /// What is wrong with the line.
+    pub reason: String,
+}
+
+impl Malformed {
+    pub fn new<R: Display>(reason: R) -> Self {
+        Malformed {
+            line: 0,
+            offset: 0,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Point the problem at the given byte `offset` of the line.
+    pub fn at(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Point the problem at the given line of a multi-line record.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Attribute the problem to the record that starts at the given 1-based `line` of `input`.
+    pub(crate) fn into_error(self, input: &str, line: usize, record: &[String]) -> Error {
+        let snippet = record.get(self.line).cloned().unwrap_or_default();
+        let offset = self.offset.min(snippet.len());
+        Error::Parse {
+            input: input.to_owned(),
+            line: line + self.line,
+            column: snippet.get(..offset).map_or(0, |s| s.chars().count()) + 1,
+            snippet,
+            reason: self.reason,
+        }
+    }
+}
+
+impl Display for Malformed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+// NOTE: `Malformed` must not implement `std::error::Error` itself, or this would conflict with the
+// blanket `impl<T> From<T> for T`.
+impl<E: std::error::Error> From<E> for Malformed {
+    fn from(err: E) -> Self {
+        Malformed::new(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_parse_error() {
+        let err = Malformed::new("invalid digit found in string")
+            .at(4)
+            .into_error("input.txt", 12, &["acc +x1 y".to_owned()]);
+        assert_eq!(
+            err.render(),
+            "error: invalid digit found in string
+  --> input.txt:12:5
+   |
+12 | acc +x1 y
+   |     ^^^"
+        );
+        assert_eq!(
+            err.to_string(),
+            "input.txt:12:5: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn malformed_on_line() {
+        let record = vec!["a:1".to_owned(), "b:2 c".to_owned()];
+        match Malformed::new("missing value")
+            .on_line(1)
+            .at(4)
+            .into_error("-", 7, &record)
+        {
+            Error::Parse {
+                line,
+                column,
+                snippet,
+                ..
+            } => {
+                assert_eq!((line, column), (8, 5));
+                assert_eq!(snippet, "b:2 c");
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+}
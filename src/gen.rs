@@ -0,0 +1,138 @@
+//! Deterministic generation of puzzle inputs of arbitrary size, for benchmarking and fuzzing the
+//! solvers beyond the examples and our own inputs.
+
+use std::fmt::{Display, Write as _};
+
+use crate::{Error, Part, Result};
+
+/// A small, seedable pseudo-random number generator (SplitMix64).
+///
+/// It is implemented here rather than pulled in as a dependency, so that the inputs generated for a
+/// given seed stay the same regardless of the versions of any crates.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `low..=high`.
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low <= high, "empty range {}..={}", low, high);
+        match (high - low).checked_add(1) {
+            Some(span) => low + ((self.next_u64() as u128 * span as u128) >> 64) as u64,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index into a collection of `len` (non-zero) elements.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.between(0, len as u64 - 1) as usize
+    }
+
+    /// `true` with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// A random element of the given (non-empty) slice.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Shuffle the given slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Fail unless the requested `size` of an input is at least `min`.
+pub fn check_size(size: usize, min: usize) -> Result<()> {
+    if size < min {
+        return Err(Error::InvalidInput(format!(
+            "the size must be at least {}; got {}",
+            min, size
+        )));
+    }
+    Ok(())
+}
+
+/// A generated puzzle input, along with the answers to it that are known by construction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: Vec<(Part, String)>,
+}
+
+impl Generated {
+    pub fn new(input: String) -> Self {
+        Generated {
+            input,
+            answers: vec![],
+        }
+    }
+
+    /// Record the known `answer` to `part`.
+    pub fn with_answer<A: Display>(mut self, part: Part, answer: A) -> Self {
+        self.answers.push((part, answer.to_string()));
+        self
+    }
+
+    /// The known answers in the format of an example's `.expected` sidecar.
+    pub fn sidecar(&self) -> String {
+        let mut ret = String::new();
+        for (part, answer) in &self.answers {
+            writeln!(ret, "{} {}", part, answer).unwrap();
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let (mut a, mut b) = (Rng::new(42), Rng::new(42));
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn bounds() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.between(3, 5)));
+            assert!(rng.index(10) < 10);
+        }
+        assert_eq!(rng.between(4, 4), 4);
+        rng.between(0, u64::MAX);
+
+        let mut items: Vec<_> = (0..50).collect();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn sidecar() {
+        let generated = Generated::new("1\n".to_owned())
+            .with_answer(Part::One, 514579)
+            .with_answer(Part::Two, "x");
+        assert_eq!(generated.sidecar(), "1 514579\n2 x\n");
+    }
+}
@@ -0,0 +1,350 @@
+//! Points, vectors and headings on the plane, as in the navigation puzzles.
+//!
+//! `x` grows to the east and `y` to the north; note that this is unlike the rows of a
+//! [`Grid`](crate::Grid), which grow to the south.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use serde::{Deserialize, Serialize};
+
+/// A position on the plane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// The distance to `other` when moving along the axes only.
+    pub fn manhattan(self, other: Point) -> u64 {
+        (other - self).manhattan()
+    }
+
+    /// The distance to `other` when moving diagonally as well, i.e. in the eight directions.
+    pub fn chebyshev(self, other: Point) -> u64 {
+        (other - self).chebyshev()
+    }
+}
+
+/// A displacement on the plane, e.g. between two [`Point`]s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Vector { x, y }
+    }
+
+    /// The length of the vector when moving along the axes only.
+    pub fn manhattan(self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// The length of the vector when moving diagonally as well.
+    pub fn chebyshev(self) -> u64 {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+
+    /// The vector rotated counter-clockwise by `quarters` quarter turns (90°), or clockwise if
+    /// negative.
+    pub fn turn_left(self, quarters: i64) -> Self {
+        match quarters.rem_euclid(4) {
+            0 => self,
+            1 => Vector::new(-self.y, self.x),
+            2 => -self,
+            _ => Vector::new(self.y, -self.x),
+        }
+    }
+
+    /// The vector rotated clockwise by `quarters` quarter turns (90°), or counter-clockwise if
+    /// negative.
+    pub fn turn_right(self, quarters: i64) -> Self {
+        self.turn_left(-quarters)
+    }
+
+    /// The vector reflected across the `x` axis, i.e. upside down.
+    pub fn reflect_x(self) -> Self {
+        Vector::new(self.x, -self.y)
+    }
+
+    /// The vector reflected across the `y` axis, i.e. left to right.
+    pub fn reflect_y(self) -> Self {
+        Vector::new(-self.x, self.y)
+    }
+}
+
+/// One of the eight directions of the compass, in clockwise order from north.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Heading {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Heading {
+    /// The four cardinal headings, in clockwise order from north.
+    pub const CARDINAL: [Heading; 4] =
+        [Heading::North, Heading::East, Heading::South, Heading::West];
+
+    /// All eight headings, in clockwise order from north.
+    pub const ALL: [Heading; 8] = [
+        Heading::North,
+        Heading::NorthEast,
+        Heading::East,
+        Heading::SouthEast,
+        Heading::South,
+        Heading::SouthWest,
+        Heading::West,
+        Heading::NorthWest,
+    ];
+
+    /// Whether this is one of the four [`CARDINAL`](Heading::CARDINAL) headings.
+    pub fn is_cardinal(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+
+    /// The heading rotated clockwise by `eighths` eighths of a turn (45°), or counter-clockwise
+    /// if negative.
+    pub fn rotate(self, eighths: i64) -> Self {
+        Heading::ALL[(self as i64 + eighths).rem_euclid(8) as usize]
+    }
+
+    /// The heading rotated counter-clockwise by `quarters` quarter turns (90°).
+    pub fn turn_left(self, quarters: i64) -> Self {
+        self.rotate(-2 * quarters)
+    }
+
+    /// The heading rotated clockwise by `quarters` quarter turns (90°).
+    pub fn turn_right(self, quarters: i64) -> Self {
+        self.rotate(2 * quarters)
+    }
+
+    /// A single step in this heading; diagonal steps move along both axes.
+    pub fn vector(self) -> Vector {
+        match self {
+            Heading::North => Vector::new(0, 1),
+            Heading::NorthEast => Vector::new(1, 1),
+            Heading::East => Vector::new(1, 0),
+            Heading::SouthEast => Vector::new(1, -1),
+            Heading::South => Vector::new(0, -1),
+            Heading::SouthWest => Vector::new(-1, -1),
+            Heading::West => Vector::new(-1, 0),
+            Heading::NorthWest => Vector::new(-1, 1),
+        }
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, v: Vector) -> Point {
+        self + -v
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, other: Vector) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        self + -other
+    }
+}
+
+impl SubAssign for Vector {
+    fn sub_assign(&mut self, other: Vector) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, k: i64) -> Vector {
+        Vector::new(self.x * k, self.y * k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    fn coordinate(rng: &mut Rng) -> i64 {
+        rng.between(0, 2000) as i64 - 1000
+    }
+
+    fn point(rng: &mut Rng) -> Point {
+        Point::new(coordinate(rng), coordinate(rng))
+    }
+
+    fn vector(rng: &mut Rng) -> Vector {
+        Vector::new(coordinate(rng), coordinate(rng))
+    }
+
+    #[test]
+    fn rotations() {
+        let v = Vector::new(3, 1);
+        assert_eq!(v.turn_left(1), Vector::new(-1, 3));
+        assert_eq!(v.turn_right(1), Vector::new(1, -3));
+        assert_eq!(v.turn_left(2), Vector::new(-3, -1));
+        assert_eq!(Heading::East.turn_left(1), Heading::North);
+        assert_eq!(Heading::East.turn_right(3), Heading::North);
+        assert_eq!(Heading::North.rotate(-1), Heading::NorthWest);
+        assert_eq!(Heading::SouthWest.rotate(3), Heading::North);
+    }
+
+    #[test]
+    fn rotations_compose() {
+        let mut rng = Rng::new(12);
+        for _ in 0..2000 {
+            let v = vector(&mut rng);
+            let (a, b) = (coordinate(&mut rng), coordinate(&mut rng));
+            assert_eq!(v.turn_left(4), v);
+            assert_eq!(v.turn_left(a).turn_left(b), v.turn_left(a + b));
+            assert_eq!(v.turn_right(a), v.turn_left(-a));
+            assert_eq!(v.turn_left(a).turn_right(a), v);
+            assert_eq!(v.turn_left(a).manhattan(), v.manhattan());
+            assert_eq!(v.turn_left(a).chebyshev(), v.chebyshev());
+            assert_eq!((v * a).turn_left(b), v.turn_left(b) * a);
+        }
+    }
+
+    #[test]
+    fn reflections() {
+        let mut rng = Rng::new(13);
+        for _ in 0..2000 {
+            let v = vector(&mut rng);
+            assert_eq!(v.reflect_x().reflect_x(), v);
+            assert_eq!(v.reflect_y().reflect_y(), v);
+            assert_eq!(v.reflect_x().reflect_y(), v.turn_left(2));
+            assert_eq!(v.reflect_x().turn_left(1), v.turn_right(1).reflect_x());
+            assert_eq!(v.reflect_x().manhattan(), v.manhattan());
+        }
+    }
+
+    #[test]
+    fn headings_turn_like_their_vectors() {
+        let mut rng = Rng::new(14);
+        for &heading in &Heading::ALL {
+            assert_eq!(heading.rotate(8), heading);
+            assert_eq!(heading.rotate(4).vector(), -heading.vector());
+            assert_eq!(heading.vector().chebyshev(), 1);
+            assert_eq!(heading.is_cardinal(), heading.vector().manhattan() == 1);
+            for _ in 0..100 {
+                let quarters = coordinate(&mut rng);
+                assert_eq!(
+                    heading.turn_left(quarters).vector(),
+                    heading.vector().turn_left(quarters)
+                );
+                assert_eq!(
+                    heading.turn_right(quarters).vector(),
+                    heading.vector().turn_right(quarters)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(Point::new(17, -8).manhattan(Point::ORIGIN), 25);
+        assert_eq!(Point::new(17, -8).chebyshev(Point::ORIGIN), 17);
+
+        let mut rng = Rng::new(15);
+        for _ in 0..2000 {
+            let (p, q, r) = (point(&mut rng), point(&mut rng), point(&mut rng));
+            assert_eq!(p.manhattan(q), q.manhattan(p));
+            assert_eq!(p.chebyshev(q), q.chebyshev(p));
+            assert!(p.manhattan(r) <= p.manhattan(q) + q.manhattan(r));
+            assert!(p.chebyshev(r) <= p.chebyshev(q) + q.chebyshev(r));
+            assert!(p.chebyshev(q) <= p.manhattan(q));
+            assert!(p.manhattan(q) <= 2 * p.chebyshev(q));
+            assert_eq!(p.manhattan(p), 0);
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut rng = Rng::new(16);
+        for _ in 0..2000 {
+            let (p, q) = (point(&mut rng), point(&mut rng));
+            let (v, w) = (vector(&mut rng), vector(&mut rng));
+            assert_eq!((p + v) - p, v);
+            assert_eq!(p + (q - p), q);
+            assert_eq!(p + v - v, p);
+            assert_eq!((p + v) + w, p + (v + w));
+            assert_eq!(v + w, w + v);
+            assert_eq!(v - w, -(w - v));
+            assert_eq!(v * 2, v + v);
+            let mut r = p;
+            r += v;
+            r -= w;
+            assert_eq!(r, p + (v - w));
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! A rectangular grid of cells, as in the maps of several puzzles, with the cells stored row by row
+//! in a single buffer.
+
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+use std::path::Path;
+
+use crate::geom::Heading;
+use crate::{input_name, open, parse_lines_with, Error, Malformed, Result};
+
+/// A position in a [`Grid`], as `(row, column)`.
+pub type Pos = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of the given `width`, with the `cells` listed row by row.
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells is not a multiple of the `width`.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not make rows of {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(row, column)`, if it is within the grid.
+    pub fn get(&self, (row, column): Pos) -> Option<&T> {
+        if row < self.height && column < self.width {
+            Some(&self.cells[row * self.width + column])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, column): Pos) -> Option<&mut T> {
+        if row < self.height && column < self.width {
+            Some(&mut self.cells[row * self.width + column])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(row, column)` of the grid repeated to the right indefinitely, if the row is
+    /// within the grid.
+    pub fn get_wrapping(&self, (row, column): Pos) -> Option<&T> {
+        self.get((row, column % self.width))
+    }
+
+    /// The cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// The position one step away from `pos` in the given `heading` (north being up), if it is
+    /// within the grid.
+    pub fn step(&self, (row, column): Pos, heading: Heading) -> Option<Pos> {
+        let v = heading.vector();
+        let row = row.checked_add_signed(-v.y as isize)?;
+        let column = column.checked_add_signed(v.x as isize)?;
+        if row < self.height && column < self.width {
+            Some((row, column))
+        } else {
+            None
+        }
+    }
+
+    /// The (up to eight) positions adjacent to `pos`, in the order of [`Heading::ALL`].
+    pub fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Heading::ALL
+            .iter()
+            .filter_map(move |&heading| self.step(pos, heading))
+    }
+
+    /// The positions from `pos` (exclusive) up to the edge of the grid in the given `heading`,
+    /// nearest first.
+    pub fn ray(&self, pos: Pos, heading: Heading) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, heading), move |&pos| self.step(pos, heading))
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<Malformed>,
+{
+    /// Parse each line of the given reader as a row of cells, one per character; all rows must be
+    /// of the same length. `input` names the reader in errors.
+    pub fn parse<B: BufRead>(reader: B, input: &str) -> Result<Self> {
+        let mut width = None;
+        let rows = parse_lines_with(reader, input, |line| {
+            if line.is_empty() {
+                return Err(Malformed::new("empty row"));
+            }
+            let row = line
+                .char_indices()
+                .map(|(offset, c)| T::try_from(c).map_err(|err| err.into().at(offset)))
+                .collect::<Result<Vec<_>, _>>()?;
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if width != row.len() => {
+                    let offset = line
+                        .char_indices()
+                        .nth(width)
+                        .map_or(line.len(), |(i, _)| i);
+                    return Err(
+                        Malformed::new(format!("expected a row of {} cells", width)).at(offset)
+                    );
+                }
+                _ => (),
+            }
+            Ok(row)
+        })?;
+        match width {
+            Some(width) => Ok(Grid::new(width, rows.into_iter().flatten().collect())),
+            None => Err(Error::InvalidInput("empty grid".to_owned())),
+        }
+    }
+
+    /// Read the grid in the given `Path`, one row per line; see [`Grid::parse`].
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        Self::parse(open(path)?, &input_name(path))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is out of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is out of the grid", pos))
+    }
+}
+
+/// Each row on a line of its own, with each cell as it displays.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A square of a map, as in `.#.` (i.e., `false`, `true`, `false`).
+    #[derive(Debug, PartialEq)]
+    struct Square(bool);
+
+    impl TryFrom<char> for Square {
+        type Error = Malformed;
+
+        fn try_from(c: char) -> Result<Self, Malformed> {
+            match c {
+                '#' => Ok(Square(true)),
+                '.' => Ok(Square(false)),
+                c => Err(Malformed::new(format!("invalid square {:?}", c))),
+            }
+        }
+    }
+
+    impl Display for Square {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", if self.0 { '#' } else { '.' })
+        }
+    }
+
+    fn parse(s: &str) -> Result<Grid<Square>> {
+        Grid::parse(s.as_bytes(), "map.txt")
+    }
+
+    /// The positions of a `rows` by `columns` grid from `pos` in the given `heading`.
+    fn ray(pos: Pos, (rows, columns): (usize, usize), heading: Heading) -> Vec<Pos> {
+        let grid = Grid::new(columns, vec![(); rows * columns]);
+        grid.ray(pos, heading).collect()
+    }
+
+    #[test]
+    fn parse_and_display() -> Result<()> {
+        let grid = parse("..#\n#..\n")?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(0, 2)], Square(true));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get_wrapping((1, 3)), Some(&Square(true)));
+        assert_eq!(grid.get_wrapping((2, 3)), None);
+        assert_eq!(grid.to_string(), "..#\n#..\n");
+
+        assert_eq!(
+            parse("..#\n#.\n").unwrap_err().to_string(),
+            "map.txt:2:3: expected a row of 3 cells"
+        );
+        assert_eq!(
+            parse("..#\n#.x\n").unwrap_err().to_string(),
+            "map.txt:2:3: invalid square 'x'"
+        );
+        assert!(parse("").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::new(3, vec![(); 9]);
+        assert_eq!(
+            grid.neighbors((1, 1)).collect::<Vec<_>>(),
+            &[
+                (0, 1),
+                (0, 2),
+                (1, 2),
+                (2, 2),
+                (2, 1),
+                (2, 0),
+                (1, 0),
+                (0, 0)
+            ]
+        );
+        assert_eq!(
+            grid.neighbors((0, 0)).collect::<Vec<_>>(),
+            &[(0, 1), (1, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbors((2, 1)).collect::<Vec<_>>(),
+            &[(1, 1), (1, 2), (2, 2), (2, 0), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn north() {
+        assert_eq!(ray((1, 1), (3, 3), Heading::North), &[(0, 1)]);
+        assert_eq!(ray((2, 2), (5, 5), Heading::North), &[(1, 2), (0, 2)]);
+        assert_eq!(ray((0, 1), (3, 3), Heading::North), &[]);
+    }
+
+    #[test]
+    fn south() {
+        assert_eq!(ray((1, 1), (3, 3), Heading::South), &[(2, 1)]);
+        assert_eq!(ray((2, 2), (5, 5), Heading::South), &[(3, 2), (4, 2)]);
+        assert_eq!(ray((2, 2), (3, 3), Heading::South), &[]);
+    }
+
+    #[test]
+    fn east() {
+        assert_eq!(ray((1, 1), (3, 3), Heading::East), &[(1, 2)]);
+        assert_eq!(ray((2, 2), (5, 5), Heading::East), &[(2, 3), (2, 4)]);
+        assert_eq!(ray((0, 2), (3, 3), Heading::East), &[]);
+    }
+
+    #[test]
+    fn west() {
+        assert_eq!(ray((1, 1), (3, 3), Heading::West), &[(1, 0)]);
+        assert_eq!(ray((2, 2), (5, 5), Heading::West), &[(2, 1), (2, 0)]);
+        assert_eq!(ray((2, 0), (3, 3), Heading::West), &[]);
+    }
+
+    #[test]
+    fn north_east() {
+        assert_eq!(ray((1, 1), (3, 3), Heading::NorthEast), &[(0, 2)]);
+        assert_eq!(ray((2, 2), (5, 5), Heading::NorthEast), &[(1, 3), (0, 4)]);
+        assert_eq!(ray((2, 1), (3, 3), Heading::NorthEast), &[(1, 2)]);
+    }
+
+    #[test]
+    fn north_west() {
+        assert_eq!(ray((1, 1), (3, 3), Heading::NorthWest), &[(0, 0)]);
+        assert_eq!(ray((2, 2), (5, 5), Heading::NorthWest), &[(1, 1), (0, 0)]);
+        assert_eq!(ray((1, 2), (3, 3), Heading::NorthWest), &[(0, 1)]);
+    }
+
+    #[test]
+    fn south_east() {
+        assert_eq!(ray((1, 1), (3, 3), Heading::SouthEast), &[(2, 2)]);
+        assert_eq!(ray((2, 2), (5, 5), Heading::SouthEast), &[(3, 3), (4, 4)]);
+        assert_eq!(ray((0, 1), (3, 3), Heading::SouthEast), &[(1, 2)]);
+    }
+
+    #[test]
+    fn south_west() {
+        assert_eq!(ray((1, 1), (3, 3), Heading::SouthWest), &[(2, 0)]);
+        assert_eq!(ray((2, 2), (5, 5), Heading::SouthWest), &[(3, 1), (4, 0)]);
+        assert_eq!(ray((1, 2), (3, 3), Heading::SouthWest), &[(2, 1)]);
+    }
+}
@@ -1,35 +1,876 @@
+use std::cell::RefCell;
+use std::fmt::Display;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, Lines, Read};
 use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::anyhow;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
 
-/// Read and parse all `u64` integers in the given `Path`.
-pub fn read_u64s<P>(path: P) -> Result<Vec<u64>>
+mod answers;
+pub mod bench;
+pub mod client;
+pub mod config;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+mod error;
+pub mod gen;
+pub mod geom;
+pub mod grid;
+pub mod lint;
+pub mod memory;
+pub mod render;
+pub mod report;
+pub mod runner;
+mod sidecar;
+pub mod submissions;
+#[cfg(test)]
+mod testing;
+pub mod watch;
+
+pub use answers::{examples, is_example, read_answers, read_sidecar, Example, Expected};
+pub use bench::Stage;
+pub use error::{Error, Malformed, Result};
+pub use gen::{Generated, Rng};
+pub use grid::Grid;
+pub use render::{Frame, Visualize};
+pub use report::{details, Details, Explanation, Format, Report};
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: memory::Accounting = memory::Accounting;
+
+/// One of the two parts of each day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "a" => Ok(Part::One),
+            "2" | "b" => Ok(Part::Two),
+            _ => Err(anyhow!("Unknown part {:?} (expected 1 or 2)", s)),
+        }
+    }
+}
+
+impl serde::Serialize for Part {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match self {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A day's puzzle, split into parsing the input (once) and solving each of its two parts on the
+/// parsed representation.
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+    /// The answer to the first part.
+    type Answer1: Display;
+    /// The answer to the second part.
+    type Answer2: Display;
+
+    /// The day of the puzzle, in `1..=25`.
+    fn day(&self) -> u8;
+
+    /// The input file that is used when none is provided explicitly.
+    fn default_input(&self) -> &'static str;
+
+    /// The parts of the puzzle that have been solved.
+    fn parts(&self) -> &'static [Part] {
+        &Part::ALL
+    }
+
+    /// Read and parse the puzzle input in the given `Path`.
+    fn parse(&self, path: &Path) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Generate a valid input of (roughly) `size` lines or records, along with any answers to it
+    /// that are known by construction.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Result<Generated>;
+
+    /// Day-specific details on how the `answer` to the first part was reached, if any.
+    fn details1(&self, _input: &Self::Input, _answer: &Self::Answer1) -> Result<Details> {
+        Ok(Details::new())
+    }
+
+    /// Day-specific details on how the `answer` to the second part was reached, if any.
+    fn details2(&self, _input: &Self::Input, _answer: &Self::Answer2) -> Result<Details> {
+        Ok(Details::new())
+    }
+
+    /// How the `answer` to the first part was derived, step by step, if there is anything to tell
+    /// beyond the answer itself.
+    fn explain1(&self, _input: &Self::Input, _answer: &Self::Answer1) -> Result<Explanation> {
+        Ok(Explanation::new())
+    }
+
+    /// How the `answer` to the second part was derived, step by step, if there is anything to tell
+    /// beyond the answer itself.
+    fn explain2(&self, _input: &Self::Input, _answer: &Self::Answer2) -> Result<Explanation> {
+        Ok(Explanation::new())
+    }
+
+    /// The puzzle as something that can be drawn, if it can.
+    fn visualization(&self) -> Option<&dyn Visualize<Input = Self::Input>> {
+        None
+    }
+}
+
+/// Object-safe counterpart of [`Solution`], so that all days can be driven through a single
+/// registry regardless of their input and answer types.
+///
+/// Puzzles are shared between threads when several are solved at once; see [`runner`].
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn default_input(&self) -> &'static str;
+
+    fn parts(&self) -> &'static [Part];
+
+    /// The parameters of the puzzle (e.g., the `target` sum of day 1) and their current values.
+    fn params(&self) -> Map<String, Value>;
+
+    /// A copy of the puzzle with the given `params` overridden.
+    ///
+    /// An error is returned if the puzzle has no such parameter, or the value is of the wrong type.
+    fn with_params(&self, params: &Map<String, Value>) -> Result<Box<dyn Puzzle>>;
+
+    /// Parse the input in `path` once and solve each of the given `parts` on it.
+    ///
+    /// An error is returned if the input cannot be parsed; otherwise, the outcome of each part is
+    /// returned separately, in the order they were given.
+    fn solve(&self, path: &Path, parts: &[Part]) -> Result<Vec<Result<String>>>;
+
+    /// Generate an input of the given `size`; the same `seed` always yields the same input.
+    fn generate(&self, size: usize, seed: u64) -> Result<Generated>;
+
+    /// Parse the input in `path` strictly: rather than stopping at the first malformed line (or
+    /// record), return all of them, along with any other reason the input is rejected for.
+    fn check(&self, path: &Path) -> Vec<Error>;
+
+    /// Like [`solve`](Puzzle::solve), but also time each stage and collect the day-specific
+    /// details of each answer, as well as how it was derived if asked to `explain` it.
+    fn report(&self, path: &Path, parts: &[Part], explain: bool) -> Result<Vec<Result<Report>>>;
+
+    /// Parse the input in `path` and draw how the answer to `part` is reached on it; see
+    /// [`render::write`].
+    ///
+    /// An error is returned if the puzzle cannot be drawn.
+    fn render(&self, path: &Path, part: Part) -> Result<Vec<Frame>>;
+
+    /// Time parsing the input in `path` and solving each of the given `parts`, `iterations` times
+    /// each, returning the samples of each stage.
+    ///
+    /// Fails on the first error, since an answer that cannot be computed cannot be timed either.
+    /// The input is read anew on every iteration, so it cannot be the standard input (`-`).
+    fn bench(
+        &self,
+        path: &Path,
+        parts: &[Part],
+        iterations: usize,
+    ) -> Result<Vec<(Stage, Vec<Duration>)>>;
+}
+
+/// Each solution is also (de)serializable, with its fields as the parameters of the puzzle.
+impl<S> Puzzle for S
+where
+    S: Solution + Serialize + DeserializeOwned + Sync + 'static,
+{
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn default_input(&self) -> &'static str {
+        Solution::default_input(self)
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        Solution::parts(self)
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(params)) => params,
+            _ => Map::new(),
+        }
+    }
+
+    fn with_params(&self, params: &Map<String, Value>) -> Result<Box<dyn Puzzle>> {
+        let mut merged = Puzzle::params(self);
+        if merged.is_empty() && !params.is_empty() {
+            return Err(Error::InvalidInput(format!(
+                "day {} has no parameters",
+                Solution::day(self)
+            )));
+        }
+        merged.extend(params.clone());
+        let value = if merged.is_empty() {
+            Value::Null
+        } else {
+            Value::Object(merged)
+        };
+        match serde_json::from_value::<S>(value) {
+            Ok(puzzle) => Ok(Box::new(puzzle)),
+            Err(err) => Err(Error::InvalidInput(format!(
+                "day {}: {}",
+                Solution::day(self),
+                err
+            ))),
+        }
+    }
+
+    fn solve(&self, path: &Path, parts: &[Part]) -> Result<Vec<Result<String>>> {
+        let input = parse_traced(self, path)?;
+        Ok(parts
+            .iter()
+            .map(|&part| {
+                let _span = part_span(self, part).entered();
+                let answer = match part {
+                    Part::One => self.part1(&input).map(|answer| answer.to_string()),
+                    Part::Two => self.part2(&input).map(|answer| answer.to_string()),
+                };
+                trace_answer(&answer);
+                answer
+            })
+            .collect())
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Result<Generated> {
+        Solution::generate(self, &mut Rng::new(seed), size)
+    }
+
+    fn check(&self, path: &Path) -> Vec<Error> {
+        /// Stop skipping malformed lines once checking is over, even if parsing panics.
+        struct Strict;
+
+        impl Drop for Strict {
+            fn drop(&mut self) {
+                SKIPPED.with(|skipped| *skipped.borrow_mut() = None);
+            }
+        }
+
+        SKIPPED.with(|skipped| *skipped.borrow_mut() = Some(vec![]));
+        let _strict = Strict;
+        let parsed = self.parse(path);
+        let mut errors = SKIPPED
+            .with(|skipped| skipped.borrow_mut().take())
+            .unwrap_or_default();
+        if let Err(err) = parsed {
+            errors.push(err);
+        }
+        errors
+    }
+
+    fn report(&self, path: &Path, parts: &[Part], explain: bool) -> Result<Vec<Result<Report>>> {
+        let start = Instant::now();
+        let (input, parse_alloc) = memory::measure(|| parse_traced(self, path));
+        let input = input?;
+        let parse_ns = start.elapsed().as_nanos() as u64;
+        Ok(parts
+            .iter()
+            .map(|&part| {
+                let _span = part_span(self, part).entered();
+                let start = Instant::now();
+                let (answer, solve_ns, solve_alloc, details, explanation) = match part {
+                    Part::One => {
+                        let (answer, solve_alloc) = memory::measure(|| self.part1(&input));
+                        trace_answer(&answer);
+                        let answer = answer?;
+                        let solve_ns = start.elapsed().as_nanos() as u64;
+                        let details = self.details1(&input, &answer)?;
+                        let explanation = if explain {
+                            self.explain1(&input, &answer)?
+                        } else {
+                            Explanation::new()
+                        };
+                        let answer = answer.to_string();
+                        (answer, solve_ns, solve_alloc, details, explanation)
+                    }
+                    Part::Two => {
+                        let (answer, solve_alloc) = memory::measure(|| self.part2(&input));
+                        trace_answer(&answer);
+                        let answer = answer?;
+                        let solve_ns = start.elapsed().as_nanos() as u64;
+                        let details = self.details2(&input, &answer)?;
+                        let explanation = if explain {
+                            self.explain2(&input, &answer)?
+                        } else {
+                            Explanation::new()
+                        };
+                        let answer = answer.to_string();
+                        (answer, solve_ns, solve_alloc, details, explanation)
+                    }
+                };
+                Ok(Report {
+                    day: Solution::day(self),
+                    part,
+                    input: input_name(path),
+                    answer,
+                    parse_ns,
+                    solve_ns,
+                    parse_alloc,
+                    solve_alloc,
+                    explanation,
+                    details,
+                })
+            })
+            .collect())
+    }
+
+    fn render(&self, path: &Path, part: Part) -> Result<Vec<Frame>> {
+        let visualization = self.visualization().ok_or_else(|| {
+            Error::InvalidInput(format!("day {} cannot be drawn", Solution::day(self)))
+        })?;
+        let input = parse_traced(self, path)?;
+        let _span = part_span(self, part).entered();
+        visualization.frames(&input, part)
+    }
+
+    fn bench(
+        &self,
+        path: &Path,
+        parts: &[Part],
+        iterations: usize,
+    ) -> Result<Vec<(Stage, Vec<Duration>)>> {
+        let iterations = iterations.max(1);
+        let mut samples = Vec::with_capacity(iterations);
+        let mut input = None;
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let parsed = self.parse(path)?;
+            samples.push(start.elapsed());
+            input = Some(parsed);
+        }
+        let input = input.unwrap();
+
+        let mut ret = vec![(Stage::Parse, samples)];
+        for &part in parts {
+            let mut samples = Vec::with_capacity(iterations);
+            for _ in 0..iterations {
+                let start = Instant::now();
+                match part {
+                    Part::One => drop(std::hint::black_box(self.part1(&input)?)),
+                    Part::Two => drop(std::hint::black_box(self.part2(&input)?)),
+                }
+                samples.push(start.elapsed());
+            }
+            ret.push((part.into(), samples));
+        }
+        Ok(ret)
+    }
+}
+
+/// Parse the input in `path`, within a `parse` span of the day.
+fn parse_traced<S: Solution>(solution: &S, path: &Path) -> Result<S::Input> {
+    let _span =
+        tracing::info_span!("parse", day = solution.day(), input = %input_name(path)).entered();
+    let input = solution.parse(path);
+    if let Err(err) = &input {
+        tracing::info!(%err, "cannot parse the input");
+    }
+    input
+}
+
+/// The span that solving a `part` of the day runs within.
+fn part_span<S: Solution>(solution: &S, part: Part) -> tracing::Span {
+    tracing::info_span!("part", day = solution.day(), part = %part)
+}
+
+/// Log the outcome of solving a part, within its span.
+fn trace_answer<T: Display>(answer: &Result<T>) {
+    match answer {
+        Ok(answer) => tracing::info!(%answer, "solved"),
+        Err(err) => tracing::info!(%err, "cannot be solved"),
+    }
+}
+
+/// All days that have been solved so far, in order.
+pub const DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01::DEFAULT,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07::DEFAULT,
+    &day08::Day08,
+    &day09::Day09::DEFAULT,
+    &day10::Day10::DEFAULT,
+    &day11::Day11::DEFAULT,
+    &day12::Day12,
+];
+
+/// Open the input in the given `Path` for reading: `-` stands for the standard input, while files
+/// ending in `.gz` or `.zst` are decompressed on the fly.
+pub fn open<P>(path: P) -> Result<Box<dyn BufRead>>
 where
     P: AsRef<Path>,
 {
-    BufReader::with_capacity(1 << 14, File::open(path)?)
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    let file = File::open(path)?;
+    let reader: Box<dyn Read> = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("gz") => Box::new(flate2::read::MultiGzDecoder::new(file)),
+        Some("zst") => Box::new(zstd::Decoder::new(file)?),
+        _ => Box::new(file),
+    };
+    Ok(Box::new(BufReader::with_capacity(1 << 14, reader)))
+}
+
+/// The name of the input in the given `Path`, for use in diagnostics.
+fn input_name(path: &Path) -> String {
+    if path == Path::new("-") {
+        "<stdin>".to_owned()
+    } else {
+        path.display().to_string()
+    }
+}
+
+thread_local! {
+    /// The malformed lines (or records) skipped so far, while checking an input on this thread;
+    /// see [`Puzzle::check`].
+    static SKIPPED: RefCell<Option<Vec<Error>>> = const { RefCell::new(None) };
+}
+
+/// Fail with the given error for a malformed line (or record) or, if checking an input, record it
+/// and skip the line to keep looking for more.
+fn skip_malformed(err: Error) -> Result<()> {
+    SKIPPED.with(|skipped| match skipped.borrow_mut().as_mut() {
+        Some(skipped) => {
+            skipped.push(err);
+            Ok(())
+        }
+        None => Err(err),
+    })
+}
+
+/// Parse each line of the given reader with `parse`; `input` names the reader in errors.
+pub fn parse_lines_with<T, B, F>(reader: B, input: &str, mut parse: F) -> Result<Vec<T>>
+where
+    B: BufRead,
+    F: FnMut(&str) -> Result<T, Malformed>,
+{
+    let mut ret = vec![];
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        match parse(&line) {
+            Ok(parsed) => ret.push(parsed),
+            Err(err) => skip_malformed(err.into_error(input, i + 1, std::slice::from_ref(&line)))?,
+        }
+    }
+    Ok(ret)
+}
+
+/// Parse each line of the given reader as a `T`; `input` names the reader in errors.
+pub fn parse_lines<T, B>(reader: B, input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Malformed>,
+    B: BufRead,
+{
+    parse_lines_with(reader, input, |line| line.parse().map_err(Into::into))
+}
+
+/// Read and parse each line in the given `Path` with `parse`.
+///
+/// If any line is malformed, an [`Error::Parse`] is returned that points at the offending line.
+pub fn read_parsed_with<T, P, F>(path: P, parse: F) -> Result<Vec<T>>
+where
+    P: AsRef<Path>,
+    F: FnMut(&str) -> Result<T, Malformed>,
+{
+    let path = path.as_ref();
+    parse_lines_with(open(path)?, &input_name(path), parse)
+}
+
+/// Read and parse each line in the given `Path` as a `T`.
+///
+/// If any line is malformed, an [`Error::Parse`] is returned that points at the offending line.
+pub fn read_parsed<T, P>(path: P) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Malformed>,
+    P: AsRef<Path>,
+{
+    read_parsed_with(path, |line| line.parse().map_err(Into::into))
+}
+
+/// Read all lines in the given `Path`.
+pub fn read_lines<P>(path: P) -> Result<Vec<String>>
+where
+    P: AsRef<Path>,
+{
+    open(path)?
         .lines()
-        .map(|line| {
-            line.and_then(|x| {
-                x.parse::<u64>()
-                    .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
-            })
-            .map_err(anyhow::Error::from)
-            //.map_err(|err| err.into())
-        })
+        .map(|line| line.map_err(Error::from))
+        .collect()
+}
+
+/// An iterator over the records of an input, where each record is a group of consecutive
+/// non-blank lines and records are separated by one or more blank lines.
+///
+/// Leading and trailing blank lines are ignored, so the last record is yielded regardless of how
+/// many newlines the input ends with (if any).
+pub struct Groups<B> {
+    lines: Lines<B>,
+    line: usize,
+}
+
+impl<B: BufRead> Groups<B> {
+    /// Like `next`, but also yield the 1-based number of the line that the record starts at.
+    fn next_numbered(&mut self) -> Option<io::Result<(usize, Vec<String>)>> {
+        let (mut start, mut group) = (0, vec![]);
+        for line in &mut self.lines {
+            self.line += 1;
+            match line {
+                Ok(line) if line.trim().is_empty() => {
+                    if !group.is_empty() {
+                        return Some(Ok((start, group)));
+                    }
+                }
+                Ok(line) => {
+                    if group.is_empty() {
+                        start = self.line;
+                    }
+                    group.push(line);
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        if group.is_empty() {
+            None
+        } else {
+            Some(Ok((start, group)))
+        }
+    }
+}
+
+impl<B: BufRead> Iterator for Groups<B> {
+    type Item = io::Result<Vec<String>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_numbered()
+            .map(|group| group.map(|(_, group)| group))
+    }
+}
+
+/// Iterate over the blank-line-separated records of the given reader.
+pub fn groups<B: BufRead>(reader: B) -> Groups<B> {
+    Groups {
+        lines: reader.lines(),
+        line: 0,
+    }
+}
+
+/// Read all blank-line-separated records in the given `Path`; see [`Groups`].
+pub fn read_groups<P>(path: P) -> Result<Vec<Vec<String>>>
+where
+    P: AsRef<Path>,
+{
+    groups(open(path)?)
+        .map(|group| group.map_err(Error::from))
         .collect()
 }
 
+/// Parse each blank-line-separated record of the given reader with `parse`; `input` names the
+/// reader in errors.
+pub fn parse_groups_with<T, B, F>(reader: B, input: &str, mut parse: F) -> Result<Vec<T>>
+where
+    B: BufRead,
+    F: FnMut(&[String]) -> Result<T, Malformed>,
+{
+    let mut groups = groups(reader);
+    let mut ret = vec![];
+    while let Some(group) = groups.next_numbered() {
+        let (start, group) = group?;
+        match parse(&group) {
+            Ok(parsed) => ret.push(parsed),
+            Err(err) => skip_malformed(err.into_error(input, start, &group))?,
+        }
+    }
+    Ok(ret)
+}
+
+/// Read and parse each blank-line-separated record in the given `Path` with `parse`.
+///
+/// If any record is malformed, an [`Error::Parse`] is returned that points at the offending line.
+pub fn read_groups_with<T, P, F>(path: P, parse: F) -> Result<Vec<T>>
+where
+    P: AsRef<Path>,
+    F: FnMut(&[String]) -> Result<T, Malformed>,
+{
+    let path = path.as_ref();
+    parse_groups_with(open(path)?, &input_name(path), parse)
+}
+
 #[cfg(test)]
 mod tests {
+    use serde::Deserialize;
+
     use super::*;
-    use anyhow::Result;
 
     #[test]
     fn read() -> Result<()> {
-        read_u64s("day01/part1.txt")?;
+        read_parsed::<u64, _>("day01/input.txt")?;
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error_location() {
+        match parse_lines::<u32, _>("1\n2\nthree\n4\n".as_bytes(), "numbers.txt") {
+            Err(Error::Parse {
+                input,
+                line,
+                column,
+                snippet,
+                ..
+            }) => {
+                assert_eq!(input, "numbers.txt");
+                assert_eq!((line, column), (3, 1));
+                assert_eq!(snippet, "three");
+            }
+            res => panic!("unexpected result {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_groups_error_location() -> Result<()> {
+        let input = "\na b\nc\n\n\nd\ne f!\n";
+        let parse = |group: &[String]| match group.iter().position(|l| l.contains('!')) {
+            Some(i) => Err(Malformed::new("bang")
+                .on_line(i)
+                .at(group[i].find('!').unwrap())),
+            None => Ok(group.len()),
+        };
+        match parse_groups_with(input.as_bytes(), "-", parse) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (7, 4)),
+            res => panic!("unexpected result {:?}", res),
+        }
+        assert_eq!(
+            parse_groups_with("a\n\nb\nc".as_bytes(), "-", |g| Ok(g.len()))?,
+            vec![1, 2]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_lines_with_closure() -> Result<()> {
+        let parsed = parse_lines_with("a1\nb22\n".as_bytes(), "-", |line| Ok(line.len() - 1))?;
+        assert_eq!(parsed, vec![1, 2]);
+        Ok(())
+    }
+
+    fn groups_of(input: &str) -> Vec<Vec<String>> {
+        groups(input.as_bytes()).collect::<io::Result<_>>().unwrap()
+    }
+
+    #[test]
+    fn groups_trailing_newlines() {
+        let expected = vec![vec!["ab", "c"], vec!["d"]];
+        assert_eq!(groups_of("ab\nc\n\nd"), expected);
+        assert_eq!(groups_of("ab\nc\n\nd\n"), expected);
+        assert_eq!(groups_of("ab\nc\n\nd\n\n"), expected);
+        assert_eq!(groups_of("ab\nc\n\nd\n\n\n"), expected);
+    }
+
+    #[test]
+    fn groups_blank_lines() {
+        let expected = vec![vec!["ab", "c"], vec!["d"]];
+        assert_eq!(groups_of("\n\nab\nc\n\nd\n"), expected);
+        assert_eq!(groups_of("ab\nc\n\n\n\nd\n"), expected);
+        assert_eq!(groups_of("ab\nc\n  \n\t\nd\n \n"), expected);
+        assert_eq!(groups_of("ab\r\nc\r\n\r\nd\r\n\r\n"), expected);
+    }
+
+    #[test]
+    fn groups_empty() {
+        assert!(groups_of("").is_empty());
+        assert!(groups_of("\n").is_empty());
+        assert!(groups_of("\n \n\n").is_empty());
+        assert_eq!(groups_of("a"), vec![vec!["a"]]);
+    }
+
+    #[test]
+    fn read_groups_file() -> Result<()> {
+        assert_eq!(read_groups("day04/example.txt")?.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn read_compressed() -> Result<()> {
+        use std::io::Write;
+
+        let plain = read_lines("day10/example.txt")?;
+        let text = plain.join("\n") + "\n";
+        let dir = std::env::temp_dir();
+        let gz = dir.join(format!("aoc-{}.txt.gz", std::process::id()));
+        let mut encoder = flate2::write::GzEncoder::new(File::create(&gz)?, Default::default());
+        encoder.write_all(text.as_bytes())?;
+        encoder.finish()?;
+        let zst = dir.join(format!("aoc-{}.txt.zst", std::process::id()));
+        std::fs::write(&zst, zstd::encode_all(text.as_bytes(), 0)?)?;
+
+        assert_eq!(read_lines(&gz)?, plain);
+        assert_eq!(read_parsed::<u64, _>(&zst)?.len(), plain.len());
+        std::fs::remove_file(gz)?;
+        std::fs::remove_file(zst)?;
+        Ok(())
+    }
+
+    #[test]
+    fn check_all_malformed_lines() -> Result<()> {
+        let dir = testing::TempDir::new("check");
+        let path = dir.join("input.txt");
+        std::fs::write(&path, "nop +0\nacc x\njmp -1\nmul +2\n")?;
+        let errors = Puzzle::check(&day08::Day08, &path);
+        let lines: Vec<_> = errors
+            .iter()
+            .map(|err| match err {
+                Error::Parse { line, .. } => *line,
+                err => panic!("unexpected error {:?}", err),
+            })
+            .collect();
+        assert_eq!(lines, vec![2, 4]);
+        // Checking must not leave parsing lenient afterwards.
+        assert!(day08::Day08.parse(&path).is_err());
+        Ok(())
+    }
+
+    /// A day whose parser gives up halfway through the input.
+    #[derive(Serialize, Deserialize)]
+    struct Panicky;
+
+    impl Solution for Panicky {
+        type Input = Vec<i64>;
+        type Answer1 = u8;
+        type Answer2 = u8;
+
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn default_input(&self) -> &'static str {
+            "day10/example.txt"
+        }
+
+        fn parse(&self, path: &Path) -> Result<Self::Input> {
+            read_parsed_with(path, |line| match line {
+                "panic" => panic!("parsing {:?}", line),
+                line => Ok(line.parse()?),
+            })
+        }
+
+        fn generate(&self, _: &mut Rng, _: usize) -> Result<Generated> {
+            Ok(Generated::default())
+        }
+
+        fn part1(&self, _: &Self::Input) -> Result<Self::Answer1> {
+            Ok(0)
+        }
+
+        fn part2(&self, _: &Self::Input) -> Result<Self::Answer2> {
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn check_panicking_parser() -> Result<()> {
+        let dir = testing::TempDir::new("check-panic");
+        let path = dir.join("input.txt");
+        std::fs::write(&path, "1\nx\npanic\n")?;
+        let checked = std::panic::catch_unwind(|| Puzzle::check(&Panicky, &path));
+        assert!(checked.is_err());
+        // Even a panic must not leave parsing lenient afterwards.
+        std::fs::write(&path, "1\nx\n")?;
+        assert!(Panicky.parse(&path).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn explain_on_request() -> Result<()> {
+        let path = Path::new("day08/example.txt");
+        let mut reports = Puzzle::report(&day08::Day08, path, &[Part::Two], false)?;
+        assert!(reports.remove(0)?.explanation.is_empty());
+        let mut reports = Puzzle::report(&day08::Day08, path, &[Part::Two], true)?;
+        assert_eq!(
+            reports.remove(0)?.explanation,
+            vec![
+                "line 8: patched `jmp -4` into `nop -4`",
+                "the program then terminates with 8 in the accumulator",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn render_on_request() -> Result<()> {
+        let frames = Puzzle::render(
+            &day11::Day11::DEFAULT,
+            "day11/example.txt".as_ref(),
+            Part::One,
+        )?;
+        assert_eq!(frames.len(), 6);
+        let frames = Puzzle::render(&day12::Day12, "day12/example.txt".as_ref(), Part::One)?;
+        assert_eq!(frames.len(), 5);
+        // Day 1 has nothing to draw, even though its input is fine.
+        match Puzzle::render(
+            &day01::Day01::DEFAULT,
+            "day01/input.txt".as_ref(),
+            Part::One,
+        ) {
+            Err(Error::InvalidInput(reason)) => assert_eq!(reason, "day 1 cannot be drawn"),
+            res => panic!("unexpected result {:?}", res.map(|frames| frames.len())),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_part() -> anyhow::Result<()> {
+        assert_eq!("1".parse::<Part>()?, Part::One);
+        assert_eq!("b".parse::<Part>()?, Part::Two);
+        assert!("3".parse::<Part>().is_err());
         Ok(())
     }
 }
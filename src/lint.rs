@@ -0,0 +1,92 @@
+//! Checking puzzle inputs for signs of having been mangled (e.g., when copied over from the
+//! browser) that the parsers tolerate, or that make them fail in confusing ways.
+
+use crate::{Error, Malformed};
+
+/// Check the raw `contents` of an input for CRLF line endings, trailing whitespace, non-ASCII
+/// characters and a missing trailing newline; `input` names it in the errors.
+///
+/// CRLF line endings are reported once, at the first line that ends in one.
+pub fn check_text(contents: &[u8], input: &str) -> Vec<Error> {
+    let mut errors = vec![];
+    // The first line that ends in CRLF, and how many do.
+    let (mut first_crlf, mut crlf_lines) = (None, 0);
+    let body = contents.strip_suffix(b"\n").unwrap_or(contents);
+    for (i, line) in body.split(|&b| b == b'\n').enumerate() {
+        let (line, cr) = match line.strip_suffix(b"\r") {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+        let snippet = String::from_utf8_lossy(line).into_owned();
+        let at = |malformed: Malformed| {
+            malformed.into_error(input, i + 1, std::slice::from_ref(&snippet))
+        };
+        if cr {
+            if first_crlf.is_none() {
+                first_crlf = Some((i, snippet.clone()));
+            }
+            crlf_lines += 1;
+        }
+        if let Some(offset) = snippet.find(|c: char| !c.is_ascii()) {
+            errors.push(at(Malformed::new("non-ASCII character").at(offset)));
+        }
+        let trimmed = snippet.trim_end();
+        if trimmed.len() < snippet.len() {
+            errors.push(at(Malformed::new("trailing whitespace").at(trimmed.len())));
+        }
+    }
+    if let Some((i, snippet)) = first_crlf {
+        let reason = match crlf_lines {
+            1 => "CRLF line ending".to_owned(),
+            n => format!("CRLF line ending (and {} more like it)", n - 1),
+        };
+        let at = snippet.len();
+        errors.insert(
+            0,
+            Malformed::new(reason)
+                .at(at)
+                .into_error(input, i + 1, &[snippet]),
+        );
+    }
+    if !contents.is_empty() && !contents.ends_with(b"\n") {
+        errors.push(Error::InvalidInput(
+            "the input does not end with a newline; was it truncated?".to_owned(),
+        ));
+    }
+    if contents.is_empty() {
+        errors.push(Error::InvalidInput("the input is empty".to_owned()));
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(contents: &str) -> Vec<String> {
+        check_text(contents.as_bytes(), "input.txt")
+            .iter()
+            .map(Error::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn clean() {
+        assert!(check("1721\n979\n").is_empty());
+        assert!(check("a\n\nb\n").is_empty());
+    }
+
+    #[test]
+    fn problems() {
+        assert_eq!(
+            check("1721\r\n979 \r\n36é\r\n299"),
+            vec![
+                "input.txt:1:5: CRLF line ending (and 2 more like it)",
+                "input.txt:2:4: trailing whitespace",
+                "input.txt:3:3: non-ASCII character",
+                "invalid input: the input does not end with a newline; was it truncated?",
+            ]
+        );
+        assert_eq!(check(""), vec!["invalid input: the input is empty"]);
+    }
+}
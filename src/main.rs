@@ -0,0 +1,1227 @@
+use std::io::{IsTerminal, Read};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Result};
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use tracing_subscriber::fmt::{self, format::FmtSpan};
+use tracing_subscriber::prelude::*;
+
+use aoc2020::bench::{self, Measurement};
+use aoc2020::client::{self, Client, Outcome};
+use aoc2020::config::{self, Config, CONFIG};
+use aoc2020::lint;
+#[cfg(feature = "alloc-stats")]
+use aoc2020::memory::Accounting;
+use aoc2020::runner::{self, Job, Limits};
+use aoc2020::submissions::{self, Submission};
+use aoc2020::watch::Watcher;
+use aoc2020::{read_answers, Example, Format, Part, Puzzle, Report, DAYS};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: Accounting = Accounting;
+
+const USAGE: &str = "Usage:
+\t$ aoc run <day> [<part>] [--input <file>] [--param <name>=<value>]... [--format text|json] [--explain] [--render <file>] [--timeout <seconds>] [--memory <MiB>]
+\t$ aoc run --all [--jobs <n>] [--format text|json] [--timeout <seconds>] [--memory <MiB>]
+\t$ aoc verify [<day>] [--answers <file>]
+\t$ aoc check-input <day> [<file>]
+\t$ aoc watch <day> [--input <file>] [--param <name>=<value>]...
+\t$ aoc config show [<day>]
+\t$ aoc fetch <day>... [--base-url <url>]
+\t$ aoc submit <day> <part> [--input <file>] [--base-url <url>]
+\t$ aoc gen <day> [--size <n>] [--seed <n>] [--output <file>]
+\t$ aoc bench [<day>] [--iterations <n>] [--output <file>] [--baseline <file>] [--threshold <percent>]
+
+Every command also takes:
+\t-v, -vv, -vvv\tlog what the solvers do on the standard error (or as much as $RUST_LOG asks for)
+\t--trace <file>\twrite it as a Chrome trace too, to be viewed as a flame chart";
+
+/// The file holding the expected answers, relative to the root of the repository.
+const ANSWERS: &str = "answers.txt";
+
+/// The environment variable holding the session token, which takes precedence over [`SESSION`].
+const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The file holding the session token, if it is not provided through [`SESSION_VAR`].
+const SESSION: &str = ".aoc-session";
+
+/// The file that submitted answers are recorded in, along with their verdicts.
+const SUBMISSIONS: &str = "submissions.txt";
+
+/// The file that benchmark results are written in, unless specified otherwise.
+const BENCH_OUTPUT: &str = "bench.json";
+
+/// The environment variable holding the path of the configuration, which takes precedence over
+/// [`CONFIG`].
+const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Read the configuration in `$AOC_CONFIG` or else in [`CONFIG`], if there is one at all.
+fn read_config() -> Result<Config> {
+    let config = match std::env::var(CONFIG_VAR) {
+        Ok(path) => Config::read(path),
+        Err(_) if Path::new(CONFIG).exists() => Config::read(CONFIG),
+        Err(_) => Ok(Config::default()),
+    };
+    config.map_err(|err| anyhow!("{}", err.render()))
+}
+
+/// The puzzle of the given `day`, with its parameters as configured in `config`.
+fn configure_day(config: &Config, day: &str) -> Result<&'static dyn Puzzle> {
+    config
+        .configure(find_day(day)?)
+        .map_err(|err| anyhow!("{}", err.render()))
+}
+
+fn find_day(day: &str) -> Result<&'static dyn Puzzle> {
+    let day = day
+        .parse::<u8>()
+        .map_err(|_| anyhow!("<day> must be a number; got {:?}", day))?;
+    DAYS.iter()
+        .copied()
+        .find(|puzzle| puzzle.day() == day)
+        .ok_or_else(|| anyhow!("Day {} has not been solved", day))
+}
+
+/// Solve the given `parts` of `puzzle` on the input in `path` within the given `limits`, printing
+/// each answer in the given `format` (or each error, on the standard error), along with how it was
+/// derived if asked to `explain` it.
+///
+/// Returns `false` if any of the parts failed.
+fn run(
+    puzzle: &'static dyn Puzzle,
+    parts: &[Part],
+    path: &Path,
+    limits: Limits,
+    format: Format,
+    explain: bool,
+) -> bool {
+    let jobs = [Job {
+        puzzle,
+        parts: parts.to_vec(),
+        path: path.to_owned(),
+        explain,
+    }];
+    let mut ok = true;
+    for done in runner::run(&jobs, 1, limits) {
+        match report(&done.outcome) {
+            Ok(report) => match format {
+                Format::Text => {
+                    println!("Day {:02} part {}: {}", done.day, done.part, report.answer);
+                    if let (Some(parse), Some(solve)) = (report.parse_alloc, report.solve_alloc) {
+                        // Each as `<parse> / <part>`, as in the table of `--all`.
+                        println!(
+                            "    {} / {} allocations, {} / {} allocated, {} / {} peak",
+                            parse.allocations,
+                            solve.allocations,
+                            format_bytes(parse.bytes),
+                            format_bytes(solve.bytes),
+                            format_bytes(parse.peak),
+                            format_bytes(solve.peak)
+                        );
+                    }
+                    for step in &report.explanation {
+                        println!("    {}", step);
+                    }
+                }
+                Format::Json => print_json(report),
+            },
+            Err((_, reason)) => {
+                eprintln!(
+                    "Day {:02} part {} ({}): {}",
+                    done.day,
+                    done.part,
+                    done.path.display(),
+                    reason
+                );
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+/// The report of a job, or a short label and a description of why it did not produce an answer.
+fn report(outcome: &runner::Outcome) -> Result<&Report, (&'static str, String)> {
+    match outcome {
+        runner::Outcome::Solved(report) => Ok(report),
+        runner::Outcome::Failed(err) => Err(("(failed)", err.render())),
+        runner::Outcome::Panicked(message) => Err(("(panicked)", format!("panicked: {}", message))),
+        runner::Outcome::TimedOut(timeout) => {
+            Err(("(timed out)", format!("timed out after {:?}", timeout)))
+        }
+        runner::Outcome::OutOfMemory(cap) => Err((
+            "(out of memory)",
+            format!("tried to allocate more than {} MiB", cap >> 20),
+        )),
+    }
+}
+
+fn print_json(report: &Report) {
+    println!(
+        "{}",
+        serde_json::to_string(report).expect("reports are serializable")
+    );
+}
+
+fn cmd_run(args: &[String]) -> Result<()> {
+    let mut positional = vec![];
+    let mut input = None;
+    let mut params = vec![];
+    let mut all = false;
+    let mut explain = false;
+    let mut render = None;
+    let mut jobs = None;
+    let mut limits = Limits::default();
+    let mut format = Format::Text;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name| {
+            args.next()
+                .ok_or_else(|| anyhow!("{} requires a value\n{}", name, USAGE))
+        };
+        match arg.as_str() {
+            "--all" => all = true,
+            "--explain" => explain = true,
+            "--input" => input = Some(value(arg)?),
+            "--param" => {
+                params.push(config::parse_param(value(arg)?).map_err(|err| anyhow!("{}", err))?)
+            }
+            "--format" => format = value(arg)?.parse()?,
+            "--render" => render = Some(value(arg)?),
+            "--jobs" => {
+                jobs = match value(arg)?.parse() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => bail!("--jobs must be a positive number"),
+                }
+            }
+            "--timeout" => {
+                limits.timeout = match value(arg)?.parse::<f64>() {
+                    Ok(t) if t > 0.0 && t.is_finite() => Some(Duration::from_secs_f64(t)),
+                    _ => bail!("--timeout must be a positive number of seconds"),
+                }
+            }
+            "--memory" if !cfg!(feature = "alloc-stats") => {
+                bail!("--memory requires building with `--features alloc-stats`")
+            }
+            "--memory" => {
+                limits.memory = match value(arg)?.parse::<usize>() {
+                    Ok(m) if m > 0 => Some(m.saturating_mul(1 << 20)),
+                    _ => bail!("--memory must be a positive number of MiB"),
+                }
+            }
+            flag if flag.starts_with("--") => bail!("Unknown option {:?}\n{}", flag, USAGE),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut config = read_config()?;
+    let ok = match (all, positional.as_slice(), input) {
+        (true, [], None) if params.is_empty() && !explain && render.is_none() => {
+            let jobs = jobs.unwrap_or_else(|| {
+                thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
+            });
+            run_all(&config, jobs, limits, format)?
+        }
+        (false, [day], input) | (false, [day, _], input) if jobs.is_none() => {
+            // The command-line flags take precedence over the configuration.
+            let overrides = config.day_mut(find_day(day)?.day());
+            overrides.params.extend(params);
+            if let Some(input) = input {
+                overrides.input = Some(input.clone());
+            }
+            let puzzle = configure_day(&config, day)?;
+            let parts = match positional.get(1) {
+                Some(part) => vec![part.parse()?],
+                None => puzzle.parts().to_vec(),
+            };
+            if let Some(file) = render {
+                return draw(
+                    puzzle,
+                    &parts,
+                    Path::new(config.input(puzzle)),
+                    Path::new(file),
+                );
+            }
+            run(
+                puzzle,
+                &parts,
+                Path::new(config.input(puzzle)),
+                limits,
+                format,
+                explain,
+            )
+        }
+        _ => bail!("{}", USAGE),
+    };
+    if !ok {
+        bail!("Some puzzles could not be solved");
+    }
+    Ok(())
+}
+
+/// Draw how the answer to the single part among `parts` is reached on the input in `path`, and
+/// write it to `file`.
+fn draw(puzzle: &dyn Puzzle, parts: &[Part], path: &Path, file: &Path) -> Result<()> {
+    let part = match parts {
+        [part] => *part,
+        _ => bail!("--render draws a single part, which must be given"),
+    };
+    let frames = puzzle
+        .render(path, part)
+        .and_then(|frames| {
+            aoc2020::render::write(file, &frames)?;
+            Ok(frames)
+        })
+        .map_err(|err| anyhow!("Day {:02} part {}: {}", puzzle.day(), part, err.render()))?;
+    println!(
+        "Rendered {} frame{} to {}",
+        frames.len(),
+        if frames.len() == 1 { "" } else { "s" },
+        file.display()
+    );
+    Ok(())
+}
+
+/// Solve every part of every day as configured in `config`, on `jobs` threads and within the given
+/// `limits`, and print the answers in the given `format`: a table sorted by day and part, followed
+/// by the total wall time, or one JSON object per answer. Any errors are printed on the standard
+/// error.
+///
+/// Returns `false` if any of the parts failed.
+fn run_all(config: &Config, jobs: usize, limits: Limits, format: Format) -> Result<bool> {
+    let puzzles = DAYS
+        .iter()
+        .map(|&puzzle| config.configure(puzzle))
+        .collect::<aoc2020::Result<Vec<_>>>()
+        .map_err(|err| anyhow!("{}", err.render()))?;
+    let start = Instant::now();
+    let finished = runner::run(&Job::all(&puzzles, config), jobs, limits);
+    let wall = start.elapsed();
+
+    let mut ok = true;
+    let mut rows = vec![];
+    let mut alloc_stats = false;
+    for done in &finished {
+        let mut usage = vec![];
+        let answer = match report(&done.outcome) {
+            Ok(report) => {
+                if format == Format::Json {
+                    print_json(report);
+                }
+                if let (Some(parse), Some(solve)) = (report.parse_alloc, report.solve_alloc) {
+                    alloc_stats = true;
+                    usage = vec![
+                        format!("{} / {}", parse.allocations, solve.allocations),
+                        format!(
+                            "{} / {}",
+                            format_bytes(parse.bytes),
+                            format_bytes(solve.bytes)
+                        ),
+                        format!(
+                            "{} / {}",
+                            format_bytes(parse.peak),
+                            format_bytes(solve.peak)
+                        ),
+                    ];
+                }
+                report.answer.clone()
+            }
+            Err((label, reason)) => {
+                eprintln!(
+                    "Day {:02} part {} ({}): {}",
+                    done.day,
+                    done.part,
+                    done.path.display(),
+                    reason
+                );
+                ok = false;
+                label.to_owned()
+            }
+        };
+        let mut row = vec![
+            format!("{:02}", done.day),
+            done.part.to_string(),
+            answer,
+            format!("{:.1?}", done.elapsed),
+        ];
+        row.extend(usage);
+        rows.push(row);
+    }
+    if format == Format::Text {
+        let mut header = vec!["Day", "Part", "Answer", "Time"];
+        if alloc_stats {
+            // Each as `<parse> / <part>`; see the `alloc-stats` feature.
+            header.extend(&["Allocations", "Allocated", "Peak"]);
+        }
+        print_table(&header, &rows);
+        println!();
+        println!(
+            "{} parts on {} thread(s) in {:.1?}",
+            finished.len(),
+            jobs,
+            wall
+        );
+    }
+    Ok(ok)
+}
+
+/// Print the given `rows` as a table with a `header`, left-aligning each column.
+fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<_> = header.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let print_row = |cells: &[&str]| {
+        let line: Vec<_> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    };
+    let rule: Vec<_> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    print_row(header);
+    print_row(&rule.iter().map(String::as_str).collect::<Vec<_>>());
+    for row in rows {
+        print_row(&row.iter().map(String::as_str).collect::<Vec<_>>());
+    }
+}
+
+/// Check the solvers against the expected answers to the inputs and the examples, solving each
+/// input only once.
+fn cmd_verify(args: &[String]) -> Result<()> {
+    let mut positional = vec![];
+    let mut answers = ANSWERS;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                answers = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--answers requires a <file>\n{}", USAGE))?;
+            }
+            flag if flag.starts_with("--") => bail!("Unknown option {:?}\n{}", flag, USAGE),
+            _ => positional.push(arg),
+        }
+    }
+    let day = match positional.as_slice() {
+        [] => None,
+        [day] => Some(find_day(day)?.day()),
+        _ => bail!("{}", USAGE),
+    };
+
+    let expected = read_answers(answers).map_err(|err| anyhow!("{}", err.render()))?;
+    // Group the expected answers by day and input (in order of appearance), to parse them once.
+    let mut inputs: Vec<Example> = vec![];
+    for e in expected
+        .iter()
+        .filter(|e| day.is_none_or(|day| e.day == day))
+    {
+        match inputs
+            .iter_mut()
+            .find(|input| input.day == e.day && input.input == e.input)
+        {
+            Some(input) => input.answers.push((e.part, e.answer.clone())),
+            None => inputs.push(Example {
+                day: e.day,
+                input: e.input.clone(),
+                answers: vec![(e.part, e.answer.clone())],
+            }),
+        }
+    }
+    // Along with the examples, which go before the inputs of their day.
+    let mut examples = vec![];
+    for puzzle in DAYS.iter().filter(|p| day.is_none_or(|day| p.day() == day)) {
+        let dir = PathBuf::from(format!("day{:02}", puzzle.day()));
+        if dir.is_dir() {
+            examples.extend(aoc2020::examples(puzzle.day(), dir)?);
+        }
+    }
+    examples.append(&mut inputs);
+    examples.sort_by_key(|example| example.day);
+
+    let mut rows = vec![];
+    let mut failed = 0;
+    for example in examples {
+        let parts: Vec<_> = example.answers.iter().map(|&(part, _)| part).collect();
+        let actual: Vec<Result<String, String>> = match find_day(&example.day.to_string()) {
+            Ok(puzzle) => match puzzle.solve(&example.input, &parts) {
+                Ok(answers) => answers
+                    .into_iter()
+                    .map(|answer| answer.map_err(|err| err.to_string()))
+                    .collect(),
+                Err(err) => vec![Err(err.to_string()); parts.len()],
+            },
+            Err(err) => vec![Err(err.to_string()); parts.len()],
+        };
+        for ((part, expected), actual) in example.answers.iter().zip(actual) {
+            let (actual, status) = match actual {
+                Ok(actual) if actual == *expected => (actual, "ok"),
+                Ok(actual) => (actual, "FAIL"),
+                Err(err) => (err, "ERROR"),
+            };
+            if status != "ok" {
+                failed += 1;
+            }
+            rows.push(vec![
+                format!("{:02}", example.day),
+                part.to_string(),
+                example.input.display().to_string(),
+                expected.clone(),
+                actual,
+                status.to_owned(),
+            ]);
+        }
+    }
+
+    print_table(
+        &["Day", "Part", "Input", "Expected", "Actual", "Status"],
+        &rows,
+    );
+    println!();
+    println!("{} passed; {} failed", rows.len() - failed, failed);
+    if failed > 0 {
+        bail!("{} of {} answers do not match", failed, rows.len());
+    }
+    Ok(())
+}
+
+/// Solve a day on its input and check it against its examples, and do so again whenever any of
+/// them changes, printing how each answer changed since the previous run.
+fn cmd_watch(args: &[String]) -> Result<()> {
+    let mut positional = vec![];
+    let mut input = None;
+    let mut params = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name| {
+            args.next()
+                .ok_or_else(|| anyhow!("{} requires a value\n{}", name, USAGE))
+        };
+        match arg.as_str() {
+            "--input" => input = Some(value(arg)?),
+            "--param" => {
+                params.push(config::parse_param(value(arg)?).map_err(|err| anyhow!("{}", err))?)
+            }
+            flag if flag.starts_with("--") => bail!("Unknown option {:?}\n{}", flag, USAGE),
+            _ => positional.push(arg),
+        }
+    }
+    let day = match positional.as_slice() {
+        [day] => day,
+        _ => bail!("{}", USAGE),
+    };
+
+    let mut config = read_config()?;
+    let overrides = config.day_mut(find_day(day)?.day());
+    overrides.params.extend(params);
+    if let Some(input) = input {
+        overrides.input = Some(input.clone());
+    }
+    let puzzle = configure_day(&config, day)?;
+    let input = Path::new(config.input(puzzle));
+    if input == Path::new("-") {
+        bail!("watch reads the input on every change, so it cannot read the standard input");
+    }
+    let dir = PathBuf::from(format!("day{:02}", puzzle.day()));
+    let watcher = Watcher::new(input, &dir).map_err(|err| anyhow!("{}", err.render()))?;
+
+    let mut previous = vec![];
+    loop {
+        check_examples(find_day(day)?, &dir);
+        previous = solve_watched(puzzle, input, &previous);
+        println!();
+        println!(
+            "Watching {} and the examples in {} for changes...",
+            input.display(),
+            dir.display()
+        );
+        let changed = watcher.wait().map_err(|err| anyhow!("{}", err.render()))?;
+        println!();
+        for path in changed {
+            println!("Changed: {}", path.display());
+        }
+    }
+}
+
+/// Check `puzzle` against the examples in `dir` (with the default parameters, which the expected
+/// answers are for), printing a table of the answers that do not match, if any.
+fn check_examples(puzzle: &'static dyn Puzzle, dir: &Path) {
+    let examples = match aoc2020::examples(puzzle.day(), dir) {
+        Ok(examples) => examples,
+        Err(err) => {
+            eprintln!("{}: {}", dir.display(), err.render());
+            return;
+        }
+    };
+    let mut rows = vec![];
+    let mut checked = 0;
+    for example in examples {
+        let jobs = [Job {
+            puzzle,
+            parts: example.answers.iter().map(|&(part, _)| part).collect(),
+            path: example.input.clone(),
+            explain: false,
+        }];
+        for (done, (_, expected)) in runner::run(&jobs, 1, Limits::default())
+            .iter()
+            .zip(&example.answers)
+        {
+            checked += 1;
+            let (actual, status) = match report(&done.outcome) {
+                Ok(report) if report.answer == *expected => continue,
+                Ok(report) => (report.answer.clone(), "FAIL"),
+                Err((_, reason)) => (reason, "ERROR"),
+            };
+            rows.push(vec![
+                done.part.to_string(),
+                done.path.display().to_string(),
+                expected.clone(),
+                actual,
+                status.to_owned(),
+            ]);
+        }
+    }
+    if !rows.is_empty() {
+        print_table(&["Part", "Input", "Expected", "Actual", "Status"], &rows);
+    }
+    println!(
+        "Examples: {} passed; {} failed",
+        checked - rows.len(),
+        rows.len()
+    );
+}
+
+/// Solve every part of `puzzle` on the input in `path`, printing each answer along with the one of
+/// the `previous` run (if any, and if it is different), and return the answers.
+fn solve_watched(puzzle: &'static dyn Puzzle, path: &Path, previous: &[String]) -> Vec<String> {
+    let jobs = [Job {
+        puzzle,
+        parts: puzzle.parts().to_vec(),
+        path: path.to_owned(),
+        explain: false,
+    }];
+    let mut answers = vec![];
+    for (i, done) in runner::run(&jobs, 1, Limits::default()).iter().enumerate() {
+        let answer = match report(&done.outcome) {
+            Ok(report) => report.answer.clone(),
+            Err((label, reason)) => {
+                eprintln!(
+                    "Day {:02} part {} ({}): {}",
+                    done.day,
+                    done.part,
+                    done.path.display(),
+                    reason
+                );
+                label.to_owned()
+            }
+        };
+        let change = match previous.get(i) {
+            None => String::new(),
+            Some(previous) if *previous == answer => " (unchanged)".to_owned(),
+            Some(previous) => format!(" (was {})", previous),
+        };
+        println!(
+            "Day {:02} part {}: {}{} in {:.1?}",
+            done.day, done.part, answer, change, done.elapsed
+        );
+        answers.push(answer);
+    }
+    answers
+}
+
+/// Connect to the puzzle server at `base_url` (or `$AOC_BASE_URL`, or the real one), using the
+/// session token in `$AOC_SESSION` or the `.aoc-session` file.
+fn connect(base_url: Option<&str>) -> Result<Client> {
+    let session = match std::env::var(SESSION_VAR) {
+        Ok(session) => session,
+        Err(_) => std::fs::read_to_string(SESSION).map_err(|err| {
+            anyhow!(
+                "No session token: set ${} or store it in {} ({})",
+                SESSION_VAR,
+                SESSION,
+                err
+            )
+        })?,
+    };
+    let base_url = match base_url {
+        Some(url) => url.to_owned(),
+        None => std::env::var("AOC_BASE_URL").unwrap_or_else(|_| client::BASE_URL.to_owned()),
+    };
+    Ok(Client::new(&base_url, &session))
+}
+
+/// Download the inputs of the given days into `dayNN/input.txt`, skipping those already there.
+fn cmd_fetch(args: &[String]) -> Result<()> {
+    let mut days = vec![];
+    let mut base_url = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base-url" => {
+                base_url = Some(
+                    args.next()
+                        .ok_or_else(|| anyhow!("--base-url requires a <url>\n{}", USAGE))?,
+                );
+            }
+            flag if flag.starts_with("--") => bail!("Unknown option {:?}\n{}", flag, USAGE),
+            day => match day.parse::<u8>() {
+                Ok(day @ 1..=25) => days.push(day),
+                _ => bail!("<day> must be a number in 1..=25; got {:?}", day),
+            },
+        }
+    }
+    if days.is_empty() {
+        bail!("{}", USAGE);
+    }
+
+    let client = connect(base_url.map(String::as_str))?;
+    for day in days {
+        let path = PathBuf::from(format!("day{:02}/input.txt", day));
+        if client
+            .fetch_input(day, &path)
+            .map_err(|err| anyhow!("Day {:02}: {}", day, err))?
+        {
+            println!("Day {:02}: downloaded {}", day, path.display());
+        } else {
+            println!("Day {:02}: {} exists; not downloading", day, path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Solve a part of a day's puzzle and submit the answer, unless it is known to be wrong already.
+fn cmd_submit(args: &[String]) -> Result<()> {
+    let mut positional = vec![];
+    let mut input = None;
+    let mut base_url = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name| {
+            args.next()
+                .map(String::as_str)
+                .ok_or_else(|| anyhow!("{} requires a value\n{}", name, USAGE))
+        };
+        match arg.as_str() {
+            "--input" => input = Some(value(arg)?),
+            "--base-url" => base_url = Some(value(arg)?),
+            flag if flag.starts_with("--") => bail!("Unknown option {:?}\n{}", flag, USAGE),
+            _ => positional.push(arg),
+        }
+    }
+    let config = read_config()?;
+    let (puzzle, part) = match positional.as_slice() {
+        [day, part] => (configure_day(&config, day)?, part.parse::<Part>()?),
+        _ => bail!("{}", USAGE),
+    };
+    let day = puzzle.day();
+
+    let path = Path::new(input.unwrap_or_else(|| config.input(puzzle)));
+    let answer = puzzle
+        .solve(path, &[part])
+        .and_then(|mut answers| answers.remove(0))
+        .map_err(|err| anyhow!("Day {:02} part {}: {}", day, part, err.render()))?;
+    let history =
+        submissions::read_submissions(SUBMISSIONS).map_err(|err| anyhow!("{}", err.render()))?;
+    if let Some(reason) = Submission::refusal(&history, day, part, &answer) {
+        bail!("Not submitting day {:02} part {}: {}", day, part, reason);
+    }
+
+    let outcome = connect(base_url)?.submit(day, part, &answer)?;
+    if outcome.is_verdict() {
+        submissions::record(
+            SUBMISSIONS,
+            &Submission {
+                day,
+                part,
+                answer: answer.clone(),
+                outcome,
+            },
+        )?;
+    }
+    println!("Day {:02} part {}: {} ({})", day, part, answer, outcome);
+    match outcome {
+        Outcome::Correct => Ok(()),
+        Outcome::RateLimited(wait) => bail!("Try again in {}s", wait.as_secs()),
+        Outcome::AlreadySolved => bail!("Day {:02} part {} cannot be submitted", day, part),
+        _ => bail!("Wrong answer"),
+    }
+}
+
+/// Print the effective configuration of every day (or of the given one), i.e. the input and the
+/// value of each parameter, whether overridden or not.
+fn cmd_config(args: &[String]) -> Result<()> {
+    let config = read_config()?;
+    let puzzles = match args {
+        [show] if show == "show" => DAYS.to_vec(),
+        [show, day] if show == "show" => vec![find_day(day)?],
+        _ => bail!("{}", USAGE),
+    };
+    let mut days = toml::Table::new();
+    for puzzle in puzzles {
+        let puzzle = config
+            .configure(puzzle)
+            .map_err(|err| anyhow!("{}", err.render()))?;
+        let mut table = toml::Table::new();
+        table.insert("input".to_owned(), config.input(puzzle).into());
+        for (name, value) in puzzle.params() {
+            table.insert(name, toml::Value::try_from(value)?);
+        }
+        days.insert(format!("day{:02}", puzzle.day()), table.into());
+    }
+    print!("{}", toml::to_string(&days)?);
+    Ok(())
+}
+
+/// Check the input of a day for malformed lines and signs of mangling, without solving it.
+fn cmd_check_input(args: &[String]) -> Result<()> {
+    if let Some(flag) = args.iter().find(|arg| arg.starts_with("--")) {
+        bail!("Unknown option {:?}\n{}", flag, USAGE);
+    }
+    let config = read_config()?;
+    let (puzzle, path) = match args {
+        [day] => {
+            let puzzle = configure_day(&config, day)?;
+            (puzzle, config.input(puzzle))
+        }
+        [day, path] => (configure_day(&config, day)?, path.as_str()),
+        _ => bail!("{}", USAGE),
+    };
+    if path == "-" {
+        bail!("check-input reads the input twice, so it cannot read the standard input");
+    }
+    let path = Path::new(path);
+
+    let mut contents = vec![];
+    aoc2020::open(path)
+        .and_then(|mut input| Ok(input.read_to_end(&mut contents)?))
+        .map_err(|err| anyhow!("{}: {}", path.display(), err.render()))?;
+    let mut problems = lint::check_text(&contents, &path.display().to_string());
+    problems.extend(puzzle.check(path));
+    if problems.is_empty() {
+        println!("Day {:02} ({}): ok", puzzle.day(), path.display());
+        return Ok(());
+    }
+    for problem in &problems {
+        eprintln!("{}\n", problem.render());
+    }
+    bail!(
+        "Day {:02} ({}): found {} problem(s)",
+        puzzle.day(),
+        path.display(),
+        problems.len()
+    )
+}
+
+/// Generate an input for a day's puzzle, writing it to the standard output or to a file (along
+/// with a sidecar of the known answers, if any).
+fn cmd_gen(args: &[String]) -> Result<()> {
+    let mut positional = vec![];
+    let mut size = 1000;
+    let mut seed = 0;
+    let mut output = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name| {
+            args.next()
+                .map(String::as_str)
+                .ok_or_else(|| anyhow!("{} requires a value\n{}", name, USAGE))
+        };
+        match arg.as_str() {
+            "--size" => {
+                size = value(arg)?
+                    .parse()
+                    .map_err(|_| anyhow!("--size must be a number"))?
+            }
+            "--seed" => {
+                seed = value(arg)?
+                    .parse()
+                    .map_err(|_| anyhow!("--seed must be a number"))?
+            }
+            "--output" => output = Some(value(arg)?),
+            flag if flag.starts_with("--") => bail!("Unknown option {:?}\n{}", flag, USAGE),
+            _ => positional.push(arg),
+        }
+    }
+    let puzzle = match positional.as_slice() {
+        [day] => find_day(day)?,
+        _ => bail!("{}", USAGE),
+    };
+
+    let generated = puzzle
+        .generate(size, seed)
+        .map_err(|err| anyhow!("Day {:02}: {}", puzzle.day(), err.render()))?;
+    match output {
+        None => {
+            print!("{}", generated.input);
+            for (part, answer) in &generated.answers {
+                eprintln!("Day {:02} part {}: {}", puzzle.day(), part, answer);
+            }
+        }
+        Some(path) => {
+            let path = Path::new(path);
+            std::fs::write(path, &generated.input)?;
+            println!("Day {:02}: wrote {}", puzzle.day(), path.display());
+            if !generated.answers.is_empty() {
+                let sidecar = path.with_extension("expected");
+                std::fs::write(&sidecar, generated.sidecar())?;
+                println!("Day {:02}: wrote {}", puzzle.day(), sidecar.display());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Format the given number of bytes with a sensible binary unit, e.g. `1.2MiB`.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1}{}", value, UNITS[unit])
+}
+
+/// Format the given duration in nanoseconds with a sensible unit, e.g. `1.2ms`.
+fn format_nanos(nanos: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos))
+}
+
+/// Time parsing and each part of the selected days on their default inputs, write the results as
+/// JSON and, if a baseline is given, fail on any stage whose median got slower than the threshold.
+fn cmd_bench(args: &[String]) -> Result<()> {
+    let mut positional = vec![];
+    let mut iterations = 10;
+    let mut output = BENCH_OUTPUT;
+    let mut baseline = None;
+    let mut threshold = 10.0;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name| {
+            args.next()
+                .map(String::as_str)
+                .ok_or_else(|| anyhow!("{} requires a value\n{}", name, USAGE))
+        };
+        match arg.as_str() {
+            "--iterations" => {
+                iterations = match value(arg)?.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => bail!("--iterations must be a positive number"),
+                }
+            }
+            "--output" => output = value(arg)?,
+            "--baseline" => baseline = Some(value(arg)?),
+            "--threshold" => {
+                threshold = match value(arg)?.parse() {
+                    Ok(t) if t >= 0.0 => t,
+                    _ => bail!("--threshold must be a non-negative percentage, e.g. 10"),
+                }
+            }
+            flag if flag.starts_with("--") => bail!("Unknown option {:?}\n{}", flag, USAGE),
+            _ => positional.push(arg),
+        }
+    }
+    let config = read_config()?;
+    let puzzles = match positional.as_slice() {
+        [] => DAYS
+            .iter()
+            .map(|&puzzle| config.configure(puzzle))
+            .collect::<aoc2020::Result<_>>()
+            .map_err(|err| anyhow!("{}", err.render()))?,
+        [day] => vec![configure_day(&config, day)?],
+        _ => bail!("{}", USAGE),
+    };
+    if let Some(puzzle) = puzzles.iter().find(|&&puzzle| config.input(puzzle) == "-") {
+        bail!(
+            "bench reads the input on every iteration, so day {} cannot read the standard input",
+            puzzle.day()
+        );
+    }
+    // Read the baseline first, so that a bad path is reported before spending time on benchmarks.
+    let baseline = baseline
+        .map(bench::read_measurements)
+        .transpose()
+        .map_err(|err| anyhow!("{}", err.render()))?;
+
+    let mut measurements = vec![];
+    for puzzle in puzzles {
+        let path = Path::new(config.input(puzzle));
+        let samples = puzzle
+            .bench(path, puzzle.parts(), iterations)
+            .map_err(|err| {
+                anyhow!(
+                    "Day {:02} ({}): {}",
+                    puzzle.day(),
+                    path.display(),
+                    err.render()
+                )
+            })?;
+        for (stage, samples) in samples {
+            measurements.push(Measurement::new(puzzle.day(), stage, &samples));
+        }
+    }
+    bench::write_measurements(output, &measurements)?;
+
+    let regressions = baseline.as_deref().map_or_else(Vec::new, |b| {
+        bench::regressions(b, &measurements, threshold / 100.0)
+    });
+    let rows: Vec<_> = measurements
+        .iter()
+        .map(|m| {
+            let base = baseline
+                .iter()
+                .flatten()
+                .find(|b| b.day == m.day && b.stage == m.stage);
+            let regressed = regressions
+                .iter()
+                .any(|r| r.day == m.day && r.stage == m.stage);
+            vec![
+                format!("{:02}", m.day),
+                m.stage.to_string(),
+                format_nanos(m.min_ns),
+                format_nanos(m.median_ns),
+                format_nanos(m.p95_ns),
+                base.map_or_else(String::new, |b| format_nanos(b.median_ns)),
+                base.map_or_else(String::new, |b| {
+                    let change = m.median_ns as f64 / b.median_ns.max(1) as f64 - 1.0;
+                    format!(
+                        "{:+.1}%{}",
+                        change * 100.0,
+                        if regressed { " !" } else { "" }
+                    )
+                }),
+            ]
+        })
+        .collect();
+    print_table(
+        &["Day", "Stage", "Min", "Median", "p95", "Baseline", "Change"],
+        &rows,
+    );
+    println!();
+    println!(
+        "{} iterations per stage; results written to {}",
+        iterations, output
+    );
+    if !regressions.is_empty() {
+        bail!(
+            "{} stage(s) regressed by more than {}% compared to the baseline",
+            regressions.len(),
+            threshold
+        );
+    }
+    Ok(())
+}
+
+/// The options of the commands that take a value, which is left alone even if it looks like one of
+/// the options that every command takes (e.g., `--input -v`).
+const VALUED_OPTIONS: &[&str] = &[
+    "--answers",
+    "--base-url",
+    "--baseline",
+    "--format",
+    "--input",
+    "--iterations",
+    "--jobs",
+    "--memory",
+    "--output",
+    "--param",
+    "--render",
+    "--seed",
+    "--size",
+    "--threshold",
+    "--timeout",
+];
+
+/// Take the options that every command takes out of `args`: how verbose to be (once per `v`, as in
+/// `-vv`) and the file to write a Chrome trace to, if any.
+fn take_global_options(args: &mut Vec<String>) -> Result<(usize, Option<PathBuf>)> {
+    let mut verbosity = 0;
+    let mut trace = None;
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            option if VALUED_OPTIONS.contains(&option) => i += 2,
+            "--trace" => {
+                args.remove(i);
+                if i == args.len() {
+                    bail!("--trace requires a value\n{}", USAGE);
+                }
+                trace = Some(PathBuf::from(args.remove(i)));
+            }
+            "--verbose" => {
+                args.remove(i);
+                verbosity += 1;
+            }
+            flag if flag.len() > 1
+                && flag.starts_with('-')
+                && flag[1..].bytes().all(|b| b == b'v') =>
+            {
+                verbosity += flag.len() - 1;
+                args.remove(i);
+            }
+            _ => i += 1,
+        }
+    }
+    Ok((verbosity, trace))
+}
+
+/// Log the spans and events of the solvers on the standard error, as verbosely as `-v` asks for
+/// or else as `$RUST_LOG` does (only warnings by default), and record them in a Chrome trace too,
+/// if a `trace` file is given.
+///
+/// The trace is written in full only once the returned guard is dropped.
+fn init_tracing(verbosity: usize, trace: Option<&Path>) -> Result<Option<FlushGuard>> {
+    let filter = |default: LevelFilter| match verbosity {
+        0 => EnvFilter::builder()
+            .with_default_directive(default.into())
+            .from_env_lossy(),
+        1 => EnvFilter::new("warn,aoc2020=info"),
+        2 => EnvFilter::new("warn,aoc2020=debug"),
+        _ => EnvFilter::new("warn,aoc2020=trace"),
+    };
+    let log = fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_filter(filter(LevelFilter::WARN));
+    let (chrome, guard) = match trace {
+        Some(path) => {
+            let file = std::fs::File::create(path)
+                .map_err(|err| anyhow!("Cannot create {}: {}", path.display(), err))?;
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .writer(file)
+                .include_args(true)
+                .build();
+            // The spans of parsing and of each part, at least, make up the flame chart.
+            (
+                Some(layer.with_filter(filter(LevelFilter::INFO))),
+                Some(guard),
+            )
+        }
+        None => (None, None),
+    };
+    tracing_subscriber::registry().with(log).with(chrome).init();
+    Ok(guard)
+}
+
+fn main() -> Result<()> {
+    let mut argv: Vec<_> = std::env::args().collect();
+    let (verbosity, trace) = take_global_options(&mut argv)?;
+    let _guard = init_tracing(verbosity, trace.as_deref())?;
+    match argv.get(1).map(String::as_str) {
+        Some("run") => cmd_run(&argv[2..]),
+        Some("verify") => cmd_verify(&argv[2..]),
+        Some("check-input") => cmd_check_input(&argv[2..]),
+        Some("config") => cmd_config(&argv[2..]),
+        Some("watch") => cmd_watch(&argv[2..]),
+        Some("bench") => cmd_bench(&argv[2..]),
+        Some("gen") => cmd_gen(&argv[2..]),
+        Some("fetch") => cmd_fetch(&argv[2..]),
+        Some("submit") => cmd_submit(&argv[2..]),
+        _ => bail!("{}", USAGE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Solve `part` of `day` on the example in `path`, and check the answer against `expected`.
+    fn check_example(day: u8, path: &str, part: Part, expected: &str) {
+        let puzzle = find_day(&day.to_string()).unwrap();
+        match puzzle.solve(Path::new(path), &[part]) {
+            Ok(mut answers) => match answers.remove(0) {
+                Ok(answer) => assert_eq!(answer, expected),
+                Err(err) => panic!("{}", err.render()),
+            },
+            Err(err) => panic!("{}", err.render()),
+        }
+    }
+
+    /// Generate inputs of a few sizes for each day, and check the solvers against the answers that
+    /// are known by construction.
+    #[test]
+    fn generated_inputs() {
+        let dir = std::env::temp_dir();
+        for &puzzle in DAYS {
+            for (size, seed) in [(30, 1), (100, 2), (500, 3)] {
+                let generated = puzzle.generate(size, seed).unwrap();
+                assert_eq!(puzzle.generate(size, seed).unwrap(), generated);
+                let path = dir.join(format!(
+                    "aoc-gen-{}-day{:02}-{}.txt",
+                    std::process::id(),
+                    puzzle.day(),
+                    seed
+                ));
+                std::fs::write(&path, &generated.input).unwrap();
+                let parts: Vec<_> = generated.answers.iter().map(|&(part, _)| part).collect();
+                let answers = puzzle.solve(&path, &parts);
+                std::fs::remove_file(&path).unwrap();
+                let answers = match answers {
+                    Ok(answers) => answers,
+                    Err(err) => panic!("day {} ({}): {}", puzzle.day(), size, err.render()),
+                };
+                for ((part, expected), answer) in generated.answers.iter().zip(answers) {
+                    match answer {
+                        Ok(answer) => assert_eq!(
+                            &answer,
+                            expected,
+                            "day {} part {} (size {}, seed {})",
+                            puzzle.day(),
+                            part,
+                            size,
+                            seed
+                        ),
+                        Err(err) => panic!("day {} part {}: {}", puzzle.day(), part, err.render()),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn global_options() -> Result<()> {
+        let mut args: Vec<_> = [
+            "aoc", "-v", "run", "8", "--input", "-", "-vv", "--trace", "t.json",
+        ]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+        assert_eq!(
+            take_global_options(&mut args)?,
+            (3, Some(PathBuf::from("t.json")))
+        );
+        assert_eq!(args, ["aoc", "run", "8", "--input", "-"]);
+
+        // The values of other options are left alone, however much they look like global ones.
+        let mut args: Vec<_> = [
+            "aoc", "run", "8", "--input", "-v", "--param", "x=-v", "--output", "--trace", "-v",
+        ]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+        let expected = args[..args.len() - 1].to_vec();
+        assert_eq!(take_global_options(&mut args)?, (1, None));
+        assert_eq!(args, expected);
+
+        let mut args = vec!["aoc".to_owned(), "--trace".to_owned()];
+        assert!(take_global_options(&mut args).is_err());
+        Ok(())
+    }
+
+    // One test per part of each `dayNN/example*.txt`, generated by `build.rs` from the expected
+    // answers in the accompanying `dayNN/example*.expected`.
+    include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+}
@@ -0,0 +1,169 @@
+//! Accounting for the memory that each thread has allocated, so that the runner can cap how much a
+//! single solver may use and, with the `alloc-stats` feature, report how much each stage allocated.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use serde::Serialize;
+
+/// A global allocator that keeps track of the bytes each thread has live, on top of the system
+/// allocator.
+///
+/// A thread that tries to go over its cap (see [`with_cap`]) is parked for good instead: failing
+/// the allocation would abort the whole process, and unwinding out of an allocator is undefined
+/// behaviour, while a parked thread can simply be abandoned. Caps are only enforced if this is
+/// installed as the `#[global_allocator]`, which the binary only does with the `alloc-stats`
+/// feature.
+pub struct Accounting;
+
+thread_local! {
+    /// The bytes allocated (and not yet freed) by this thread.
+    static LIVE: Cell<isize> = const { Cell::new(0) };
+    /// The most bytes this thread may have live, and the flag to raise if it tries to go over.
+    static CAP: Cell<Option<(isize, *const AtomicBool)>> = const { Cell::new(None) };
+}
+
+#[cfg(feature = "alloc-stats")]
+thread_local! {
+    /// The number of allocations made by this thread (growing reallocations included).
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    /// The total bytes allocated by this thread, regardless of whether they have been freed since.
+    static ALLOCATED: Cell<u64> = const { Cell::new(0) };
+    /// The most bytes this thread has had live at once (since the last [`measure`]).
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+/// How much a stage of solving a puzzle allocated, as measured by [`measure`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Usage {
+    pub allocations: u64,
+    /// The total bytes allocated.
+    pub bytes: u64,
+    /// The most bytes live at once, over those live when the stage started.
+    pub peak: u64,
+}
+
+/// Run `f` on the current thread and measure how much it allocated, if the `alloc-stats` feature
+/// is enabled (and [`Accounting`] is installed).
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let allocations = ALLOCATIONS.with(Cell::get);
+        let allocated = ALLOCATED.with(Cell::get);
+        let live = LIVE.with(Cell::get);
+        let peak = PEAK.with(|peak| peak.replace(live));
+        let ret = f();
+        let usage = Usage {
+            allocations: ALLOCATIONS.with(Cell::get) - allocations,
+            bytes: ALLOCATED.with(Cell::get) - allocated,
+            peak: PEAK.with(Cell::get).saturating_sub(live).max(0) as u64,
+        };
+        PEAK.with(|p| p.set(p.get().max(peak)));
+        (ret, Some(usage))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+/// Account for `size` more bytes about to be allocated by this thread.
+fn reserve(size: usize) {
+    let live = LIVE.with(|live| {
+        live.set(live.get() + size as isize);
+        live.get()
+    });
+    #[cfg(feature = "alloc-stats")]
+    {
+        ALLOCATIONS.with(|a| a.set(a.get() + 1));
+        ALLOCATED.with(|a| a.set(a.get() + size as u64));
+        PEAK.with(|peak| peak.set(peak.get().max(live)));
+    }
+    if let Some((cap, exceeded)) = CAP.with(Cell::get) {
+        if live > cap {
+            // SAFETY: the flag is kept alive by `with_cap` for as long as the cap is set.
+            unsafe { (*exceeded).store(true, Ordering::SeqCst) };
+            loop {
+                thread::park();
+            }
+        }
+    }
+}
+
+/// Account for `size` bytes freed by this thread (although possibly allocated by another one).
+fn release(size: usize) {
+    LIVE.with(|live| live.set(live.get() - size as isize));
+}
+
+unsafe impl GlobalAlloc for Accounting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        reserve(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        reserve(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        release(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size > layout.size() {
+            reserve(new_size - layout.size());
+        }
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if new_size < layout.size() {
+            release(layout.size() - new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Run `f` on the current thread, allowing it to allocate at most `cap` more bytes than are
+/// already live; if it tries to go over, `exceeded` is raised and the thread is parked for good.
+pub fn with_cap<T>(cap: usize, exceeded: Arc<AtomicBool>, f: impl FnOnce() -> T) -> T {
+    /// Lift the cap when `f` returns (or panics), before `exceeded` is dropped.
+    struct Uncap;
+
+    impl Drop for Uncap {
+        fn drop(&mut self) {
+            CAP.with(|c| c.set(None));
+        }
+    }
+
+    let live = LIVE.with(Cell::get);
+    let cap = live.saturating_add(cap.min(isize::MAX as usize) as isize);
+    CAP.with(|c| c.set(Some((cap, Arc::as_ptr(&exceeded)))));
+    let _uncap = Uncap;
+    f()
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_usage() {
+        let (_, usage) = measure(|| {
+            let a = vec![0u8; 1000];
+            drop(a);
+            let b: Vec<u64> = Vec::with_capacity(100);
+            b
+        });
+        assert_eq!(
+            usage,
+            Some(Usage {
+                allocations: 2,
+                bytes: 1800,
+                peak: 1000,
+            })
+        );
+    }
+}
@@ -0,0 +1,348 @@
+//! Drawing how a puzzle is solved, e.g. the path taken across a map or each generation of an
+//! automaton, as a still SVG or as an animated GIF or (A)PNG.
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+use crate::{Error, Part, Result};
+
+/// A color, as `[red, green, blue]`.
+pub type Rgb = [u8; 3];
+
+/// The color of anything that is not drawn over.
+pub const BACKGROUND: Rgb = [0xff, 0xff, 0xff];
+
+/// The most pixels that a picture may span in either dimension.
+const MAX_PIXELS: f64 = 800.0;
+
+/// The most pixels that a single cell may span, for small pictures.
+const MAX_CELL_PIXELS: f64 = 8.0;
+
+/// How long each frame of an animation is shown, in hundredths of a second.
+const FRAME_DELAY: u16 = 10;
+
+/// Something to draw, in units of cells; `x` grows to the right and `y` downwards.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// The unit square at `(x, y)`.
+    Cell { x: i64, y: i64, color: Rgb },
+    /// The lines through the centers of the given cells, in order.
+    Path { points: Vec<(i64, i64)>, color: Rgb },
+}
+
+/// A single picture, with its shapes drawn in order over the [`BACKGROUND`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Frame {
+    pub shapes: Vec<Shape>,
+}
+
+impl Frame {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cell(&mut self, (x, y): (i64, i64), color: Rgb) {
+        self.shapes.push(Shape::Cell { x, y, color });
+    }
+
+    pub fn path(&mut self, points: Vec<(i64, i64)>, color: Rgb) {
+        self.shapes.push(Shape::Path { points, color });
+    }
+}
+
+/// A puzzle whose solution can be drawn.
+///
+/// Implemented alongside [`Solution`](crate::Solution), which exposes it through
+/// [`visualization`](crate::Solution::visualization).
+pub trait Visualize {
+    /// The parsed puzzle input; the same as that of the solution.
+    type Input;
+
+    /// The frames that show how the answer to `part` is reached on the `input`, in order; a single
+    /// frame makes for a still picture.
+    fn frames(&self, input: &Self::Input, part: Part) -> Result<Vec<Frame>>;
+}
+
+/// Write the `frames` to the given `Path`, as an SVG of the last one, or as an animated GIF or PNG
+/// (APNG) of all of them, going by the extension of the file.
+pub fn write<P: AsRef<Path>>(path: P, frames: &[Frame]) -> Result<()> {
+    let path = path.as_ref();
+    if frames.is_empty() {
+        return Err(Error::InvalidInput("there is nothing to draw".to_owned()));
+    }
+    let canvas = Canvas::fit(frames);
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("svg") => Ok(std::fs::write(path, svg(&canvas, frames.last().unwrap()))?),
+        Some("gif") => write_gif(path, &canvas, frames),
+        Some("png") => write_png(path, &canvas, frames),
+        _ => Err(Error::InvalidInput(format!(
+            "cannot tell the format of {} (expected .svg, .gif or .png)",
+            path.display()
+        ))),
+    }
+}
+
+/// The area that all frames are drawn on, and how many pixels each cell spans.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Canvas {
+    /// The top-left cell.
+    origin: (i64, i64),
+    /// The size of the area, in cells.
+    cells: (i64, i64),
+    scale: f64,
+}
+
+impl Canvas {
+    /// The smallest area that fits every shape of every frame, so that they all line up.
+    fn fit(frames: &[Frame]) -> Self {
+        let cells = frames
+            .iter()
+            .flat_map(|frame| &frame.shapes)
+            .flat_map(|shape| {
+                let points: Box<dyn Iterator<Item = (i64, i64)>> = match shape {
+                    Shape::Cell { x, y, .. } => Box::new(std::iter::once((*x, *y))),
+                    Shape::Path { points, .. } => Box::new(points.iter().copied()),
+                };
+                points
+            });
+        let (mut min, mut max) = ((i64::MAX, i64::MAX), (i64::MIN, i64::MIN));
+        for (x, y) in cells {
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        }
+        if min.0 > max.0 {
+            (min, max) = ((0, 0), (0, 0));
+        }
+        let cells = (max.0 - min.0 + 1, max.1 - min.1 + 1);
+        let scale = (MAX_PIXELS / cells.0.max(cells.1) as f64).min(MAX_CELL_PIXELS);
+        Canvas {
+            origin: min,
+            cells,
+            scale,
+        }
+    }
+
+    /// The size of the picture, in pixels.
+    fn pixels(&self) -> (usize, usize) {
+        let side = |cells: i64| ((cells as f64 * self.scale).ceil() as usize).max(1);
+        (side(self.cells.0), side(self.cells.1))
+    }
+
+    /// The pixel that the top-left corner of the cell at `(x, y)` falls on, with fractions of a
+    /// cell (e.g., `0.5` for its center) added to both coordinates.
+    fn pixel(&self, (x, y): (i64, i64), fraction: f64) -> (f64, f64) {
+        (
+            ((x - self.origin.0) as f64 + fraction) * self.scale,
+            ((y - self.origin.1) as f64 + fraction) * self.scale,
+        )
+    }
+}
+
+/// The last frame as an SVG document, drawn at the same size as the raster formats.
+fn svg(canvas: &Canvas, frame: &Frame) -> String {
+    let hex = |[r, g, b]: Rgb| format!("#{:02x}{:02x}{:02x}", r, g, b);
+    let (width, height) = canvas.pixels();
+    let (cw, ch) = canvas.cells;
+    let mut svg = String::new();
+    // Writing to a `String` cannot fail.
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}" shape-rendering="crispEdges">"#,
+        width, height, canvas.origin.0, canvas.origin.1, cw, ch
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+        canvas.origin.0,
+        canvas.origin.1,
+        cw,
+        ch,
+        hex(BACKGROUND)
+    );
+    for shape in &frame.shapes {
+        let _ = match shape {
+            Shape::Cell { x, y, color } => writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="1" height="1" fill="{}"/>"#,
+                x,
+                y,
+                hex(*color)
+            ),
+            Shape::Path { points, color } => {
+                let points: Vec<_> = points
+                    .iter()
+                    .map(|&(x, y)| format!("{},{}", x as f64 + 0.5, y as f64 + 0.5))
+                    .collect();
+                // At least a pixel wide, however far the picture is scaled down.
+                let stroke = (1.0 / canvas.scale).max(0.2);
+                writeln!(
+                    svg,
+                    r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}"/>"#,
+                    points.join(" "),
+                    hex(*color),
+                    stroke
+                )
+            }
+        };
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Draw the `frame` on the `canvas`, returning its pixels row by row.
+fn rasterize(canvas: &Canvas, frame: &Frame) -> Vec<Rgb> {
+    let (width, height) = canvas.pixels();
+    let mut pixels = vec![BACKGROUND; width * height];
+    let mut plot = |x: f64, y: f64, color: Rgb| {
+        let (x, y) = (x as usize, y as usize);
+        if x < width && y < height {
+            pixels[y * width + x] = color;
+        }
+    };
+    for shape in &frame.shapes {
+        match shape {
+            Shape::Cell { x, y, color } => {
+                let (left, top) = canvas.pixel((*x, *y), 0.0);
+                let (right, bottom) = canvas.pixel((*x, *y), 1.0);
+                // At least a pixel, however far the picture is scaled down.
+                for py in top as usize..(bottom as usize).max(top as usize + 1) {
+                    for px in left as usize..(right as usize).max(left as usize + 1) {
+                        plot(px as f64, py as f64, *color);
+                    }
+                }
+            }
+            Shape::Path { points, color } => {
+                for pair in points.windows(2) {
+                    let (x0, y0) = canvas.pixel(pair[0], 0.5);
+                    let (x1, y1) = canvas.pixel(pair[1], 0.5);
+                    let steps = (x1 - x0).abs().max((y1 - y0).abs()).ceil().max(1.0);
+                    for i in 0..=steps as usize {
+                        let t = i as f64 / steps;
+                        plot(x0 + (x1 - x0) * t, y0 + (y1 - y0) * t, *color);
+                    }
+                }
+                if let [point] = points.as_slice() {
+                    let (x, y) = canvas.pixel(*point, 0.5);
+                    plot(x, y, *color);
+                }
+            }
+        }
+    }
+    pixels
+}
+
+fn encoding_error<E>(err: E) -> Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    Error::Io(io::Error::other(err))
+}
+
+fn write_gif(path: &Path, canvas: &Canvas, frames: &[Frame]) -> Result<()> {
+    let (width, height) = canvas.pixels();
+    let rasters: Vec<_> = frames
+        .iter()
+        .map(|frame| rasterize(canvas, frame))
+        .collect();
+    // A single palette for all frames, with the background first.
+    let mut palette = vec![BACKGROUND];
+    for &color in rasters.iter().flatten() {
+        if !palette.contains(&color) {
+            palette.push(color);
+        }
+    }
+    if palette.len() > 256 {
+        return Err(Error::InvalidInput(format!(
+            "a GIF can only have 256 colors, not {}",
+            palette.len()
+        )));
+    }
+
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = gif::Encoder::new(file, width as u16, height as u16, &palette.concat())
+        .map_err(encoding_error)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(encoding_error)?;
+    for raster in rasters {
+        let indices: Vec<u8> = raster
+            .iter()
+            .map(|color| palette.iter().position(|c| c == color).unwrap() as u8)
+            .collect();
+        let mut frame = gif::Frame::from_indexed_pixels(width as u16, height as u16, indices, None);
+        frame.delay = FRAME_DELAY;
+        encoder.write_frame(&frame).map_err(encoding_error)?;
+    }
+    Ok(())
+}
+
+fn write_png(path: &Path, canvas: &Canvas, frames: &[Frame]) -> Result<()> {
+    let (width, height) = canvas.pixels();
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    if frames.len() > 1 {
+        encoder
+            .set_animated(frames.len() as u32, 0)
+            .map_err(encoding_error)?;
+        encoder
+            .set_frame_delay(FRAME_DELAY, 100)
+            .map_err(encoding_error)?;
+    }
+    let mut writer = encoder.write_header().map_err(encoding_error)?;
+    for frame in frames {
+        writer
+            .write_image_data(&rasterize(canvas, frame).concat())
+            .map_err(encoding_error)?;
+    }
+    writer.finish().map_err(encoding_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    const RED: Rgb = [0xff, 0, 0];
+
+    fn frames() -> Vec<Frame> {
+        let mut first = Frame::new();
+        first.cell((0, 0), RED);
+        let mut second = first.clone();
+        second.path(vec![(0, 0), (3, 1)], [0, 0, 0xff]);
+        vec![first, second]
+    }
+
+    #[test]
+    fn fit_all_frames() {
+        let canvas = Canvas::fit(&frames());
+        assert_eq!(canvas.origin, (0, 0));
+        assert_eq!(canvas.cells, (4, 2));
+        assert_eq!(canvas.pixels(), (32, 16));
+
+        let pixels = rasterize(&canvas, &frames()[0]);
+        assert_eq!(pixels.len(), 32 * 16);
+        assert_eq!(pixels[0], RED);
+        assert_eq!(pixels[7 * 32 + 7], RED);
+        assert_eq!(pixels[8], BACKGROUND);
+    }
+
+    #[test]
+    fn write_formats() -> Result<()> {
+        let dir = TempDir::new("render");
+        for name in ["frames.svg", "frames.gif", "frames.png"] {
+            write(dir.join(name), &frames())?;
+            assert!(std::fs::metadata(dir.join(name))?.len() > 0);
+        }
+        let svg = std::fs::read_to_string(dir.join("frames.svg"))?;
+        assert!(
+            svg.contains(r##"<polyline points="0.5,0.5 3.5,1.5" fill="none" stroke="#0000ff""##)
+        );
+        assert!(write(dir.join("frames.bmp"), &frames()).is_err());
+        assert!(write(dir.join("empty.svg"), &[]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::memory::Usage;
+use crate::Part;
+
+/// Day-specific facts about how an answer was reached (e.g., the entries that add up to it),
+/// reported alongside the answer itself.
+pub type Details = Map<String, Value>;
+
+/// A human-readable derivation of an answer (e.g., the entries that add up to it), one step per
+/// line; reported alongside the answer when asked to explain it.
+pub type Explanation = Vec<String>;
+
+/// Build the [`Details`] out of the given `(name, value)` pairs.
+pub fn details<I, V>(fields: I) -> Details
+where
+    I: IntoIterator<Item = (&'static str, V)>,
+    V: Into<Value>,
+{
+    fields
+        .into_iter()
+        .map(|(name, value)| (name.to_owned(), value.into()))
+        .collect()
+}
+
+/// The outcome of solving a single part of a day's puzzle, along with how long it took.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+    /// The time it took to read and parse the input, shared by all parts solved on it.
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// How much parsing the input allocated, with the `alloc-stats` feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_alloc: Option<Usage>,
+    /// How much solving the part allocated, with the `alloc-stats` feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_alloc: Option<Usage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub explanation: Explanation,
+    #[serde(flatten)]
+    pub details: Details,
+}
+
+/// How the answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One human-readable line per answer.
+    Text,
+    /// One JSON object per answer and line; see [`Report`].
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(anyhow!("Unknown format {:?} (expected text or json)", s)),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flattened_details() {
+        let report = Report {
+            day: 3,
+            part: Part::Two,
+            input: "day03/example.txt".to_owned(),
+            answer: "336".to_owned(),
+            parse_ns: 10,
+            solve_ns: 20,
+            parse_alloc: None,
+            solve_alloc: Some(Usage {
+                allocations: 1,
+                bytes: 40,
+                peak: 40,
+            }),
+            explanation: vec![],
+            details: details(vec![("trees", vec![2, 7, 3, 4, 2])]),
+        };
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"day":3,"part":2,"input":"day03/example.txt","answer":"336","parse_ns":10,"solve_ns":20,"solve_alloc":{"allocations":1,"bytes":40,"peak":40},"trees":[2,7,3,4,2]}"#
+        );
+    }
+
+    #[test]
+    fn parse_format() -> anyhow::Result<()> {
+        assert_eq!("json".parse::<Format>()?, Format::Json);
+        assert!("yaml".parse::<Format>().is_err());
+        Ok(())
+    }
+}
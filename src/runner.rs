@@ -0,0 +1,379 @@
+//! Solving many puzzles concurrently on a pool of threads, so that the whole year can be run at
+//! once; a solver that panics, runs for too long or allocates too much only fails its own job.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
+use crate::{memory, Error, Part, Puzzle, Report};
+
+/// How often a running job is checked against its [`Limits`].
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The resources that each job may use, if limited.
+///
+/// A job that exceeds them is abandoned: its thread keeps running (or parked, see
+/// [`memory::Accounting`]) until the process exits, but its outcome is no longer waited for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// The wall-clock time each job may take, parsing included.
+    pub timeout: Option<Duration>,
+    /// The bytes each job may have allocated at any time.
+    pub memory: Option<usize>,
+}
+
+/// Some parts of a puzzle to be solved on the same input, which is parsed (and read, e.g. from the
+/// standard input) only once for all of them.
+///
+/// The parts share the [`Limits`] of the job: if one of them panics or exceeds them, the parts that
+/// were not solved yet fail along with it.
+#[derive(Clone)]
+pub struct Job {
+    pub puzzle: &'static dyn Puzzle,
+    pub parts: Vec<Part>,
+    pub path: PathBuf,
+    /// Whether to explain how the answer was derived; see [`Report::explanation`].
+    pub explain: bool,
+}
+
+impl Job {
+    /// Every part of every given puzzle, on its input as configured in `config`.
+    pub fn all(puzzles: &[&'static dyn Puzzle], config: &Config) -> Vec<Job> {
+        puzzles
+            .iter()
+            .map(|&puzzle| Job {
+                puzzle,
+                parts: puzzle.parts().to_vec(),
+                path: PathBuf::from(config.input(puzzle)),
+                explain: false,
+            })
+            .collect()
+    }
+}
+
+/// How a [`Job`] ended.
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(Report),
+    /// The input could not be parsed, or the part could not be solved on it; an input that cannot be
+    /// parsed fails every part of the job with the same error.
+    Failed(Arc<Error>),
+    /// The solver panicked, with the given message.
+    Panicked(String),
+    /// The solver was still running when the given timeout expired.
+    TimedOut(Duration),
+    /// The solver tried to allocate more than the given number of bytes.
+    OutOfMemory(usize),
+}
+
+/// The outcome of a part of a [`Job`], along with the time it took, parsing included: that of
+/// parsing and solving it if it was solved, or else the (wall) time the whole job took.
+#[derive(Debug)]
+pub struct Finished {
+    pub day: u8,
+    pub part: Part,
+    pub path: PathBuf,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+/// Run all `jobs` on (up to) `threads` threads, each within the given `limits`, and return the
+/// outcomes of their parts sorted by day and part.
+pub fn run(jobs: &[Job], threads: usize, limits: Limits) -> Vec<Finished> {
+    let next = AtomicUsize::new(0);
+    let finished = Mutex::new(Vec::with_capacity(jobs.len()));
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(job) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let done = run_job(job, limits);
+                    finished.lock().unwrap().extend(done);
+                }
+            });
+        }
+    });
+    let mut finished = finished.into_inner().unwrap();
+    finished.sort_by_key(|done| (done.day, done.part));
+    finished
+}
+
+/// Run the `job` on a thread of its own, so that it can be abandoned if it exceeds the `limits`,
+/// and return the outcome of each of its parts in order.
+fn run_job(job: &Job, limits: Limits) -> Vec<Finished> {
+    let start = Instant::now();
+    let exceeded = Arc::new(AtomicBool::new(false));
+    let (tx, rx) = mpsc::channel();
+    let spawned = {
+        let job = job.clone();
+        let exceeded = Arc::clone(&exceeded);
+        thread::Builder::new()
+            .name(format!("day{:02}", job.puzzle.day()))
+            .spawn(move || {
+                let solve = || job.puzzle.report(&job.path, &job.parts, job.explain);
+                let reports = panic::catch_unwind(AssertUnwindSafe(|| match limits.memory {
+                    Some(cap) => memory::with_cap(cap, exceeded, solve),
+                    None => solve(),
+                }));
+                // The job may have been abandoned in the meantime, in which case nobody listens.
+                let _ = tx.send(reports.map_err(panic_message));
+            })
+    };
+
+    // The outcome shared by all parts, if the job did not get as far as solving them one by one.
+    let every = |outcome: &dyn Fn() -> Outcome| job.parts.iter().map(|_| outcome()).collect();
+    let outcomes: Vec<Outcome> = match spawned {
+        Ok(_) => loop {
+            match rx.recv_timeout(POLL_INTERVAL) {
+                Ok(Ok(Ok(reports))) => {
+                    break reports
+                        .into_iter()
+                        .map(|report| match report {
+                            Ok(report) => Outcome::Solved(report),
+                            Err(err) => Outcome::Failed(Arc::new(err)),
+                        })
+                        .collect();
+                }
+                Ok(Ok(Err(err))) => {
+                    let err = Arc::new(err);
+                    break every(&|| Outcome::Failed(Arc::clone(&err)));
+                }
+                Ok(Err(message)) => break every(&|| Outcome::Panicked(message.clone())),
+                Err(RecvTimeoutError::Disconnected) => {
+                    break every(&|| {
+                        Outcome::Panicked("the solver thread exited unexpectedly".to_owned())
+                    })
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    if let (true, Some(cap)) = (exceeded.load(Ordering::SeqCst), limits.memory) {
+                        break every(&|| Outcome::OutOfMemory(cap));
+                    }
+                    if let Some(timeout) = limits.timeout.filter(|&t| start.elapsed() >= t) {
+                        break every(&|| Outcome::TimedOut(timeout));
+                    }
+                }
+            }
+        },
+        Err(err) => {
+            let err = Arc::new(Error::Io(err));
+            every(&|| Outcome::Failed(Arc::clone(&err)))
+        }
+    };
+    let elapsed = start.elapsed();
+    job.parts
+        .iter()
+        .zip(outcomes)
+        .map(|(&part, outcome)| Finished {
+            day: job.puzzle.day(),
+            part,
+            path: job.path.clone(),
+            elapsed: match &outcome {
+                Outcome::Solved(report) => Duration::from_nanos(report.parse_ns + report.solve_ns),
+                _ => elapsed,
+            },
+            outcome,
+        })
+        .collect()
+}
+
+/// The message that a thread panicked with, if it is a string (as with `panic!` and friends).
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => (*message).to_owned(),
+            Err(_) => "unknown panic payload".to_owned(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::testing::TempDir;
+    use crate::{Generated, Result, Rng, Solution};
+
+    #[derive(Serialize, Deserialize)]
+    struct Broken;
+
+    impl Solution for Broken {
+        type Input = ();
+        type Answer1 = u8;
+        type Answer2 = u8;
+
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn default_input(&self) -> &'static str {
+            "day10/example.txt"
+        }
+
+        fn parse(&self, _: &Path) -> Result<Self::Input> {
+            Ok(())
+        }
+
+        fn generate(&self, _: &mut Rng, _: usize) -> Result<Generated> {
+            Ok(Generated::default())
+        }
+
+        fn part1(&self, _: &Self::Input) -> Result<Self::Answer1> {
+            panic!("broken on purpose")
+        }
+
+        fn part2(&self, _: &Self::Input) -> Result<Self::Answer2> {
+            Err(Error::NoSolution("nothing to see here".to_owned()))
+        }
+    }
+
+    #[test]
+    fn isolate_panics() {
+        let mut jobs = Job::all(&[&crate::day10::Day10::DEFAULT], &Config::default());
+        // Each part of `Broken` on its own, so that the panic of the first spares the second.
+        for &part in &Part::ALL {
+            jobs.push(Job {
+                puzzle: &Broken,
+                parts: vec![part],
+                path: PathBuf::new(),
+                explain: false,
+            });
+        }
+        jobs.push(Job {
+            puzzle: &Broken,
+            parts: Part::ALL.to_vec(),
+            path: PathBuf::new(),
+            explain: false,
+        });
+        for job in &mut jobs {
+            job.path = PathBuf::from("day10/example.txt");
+        }
+        let finished = run(&jobs, 3, Limits::default());
+        // `run` sorts by day and part only, so the two parts of `Broken` that are alike may come in
+        // either order.
+        let mut outcomes: Vec<_> = finished
+            .iter()
+            .map(|done| match &done.outcome {
+                Outcome::Solved(report) => (done.day, done.part, report.answer.clone()),
+                Outcome::Failed(_) => (done.day, done.part, "failed".to_owned()),
+                Outcome::Panicked(message) => (done.day, done.part, message.clone()),
+                outcome => panic!("unexpected outcome {:?}", outcome),
+            })
+            .collect();
+        outcomes.sort();
+        assert_eq!(
+            outcomes,
+            vec![
+                (0, Part::One, "broken on purpose".to_owned()),
+                (0, Part::One, "broken on purpose".to_owned()),
+                (0, Part::Two, "broken on purpose".to_owned()),
+                (0, Part::Two, "failed".to_owned()),
+                (10, Part::One, "220".to_owned()),
+                (10, Part::Two, "19208".to_owned()),
+            ]
+        );
+    }
+
+    /// Both parts are solved on an input that can only be read once, like the standard input.
+    #[cfg(unix)]
+    #[test]
+    fn read_piped_input_once() -> Result<()> {
+        let dir = TempDir::new("runner-pipe");
+        let pipe = dir.join("input");
+        let made = std::process::Command::new("mkfifo").arg(&pipe).status()?;
+        assert!(made.success());
+        let writer = {
+            let pipe = pipe.clone();
+            thread::spawn(move || std::fs::write(pipe, std::fs::read("day10/example.txt")?))
+        };
+        let job = Job {
+            puzzle: &crate::day10::Day10::DEFAULT,
+            parts: Part::ALL.to_vec(),
+            path: pipe,
+            explain: false,
+        };
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(5)),
+            memory: None,
+        };
+        let answers: Vec<_> = run(&[job], 1, limits)
+            .into_iter()
+            .map(|done| match done.outcome {
+                Outcome::Solved(report) => report.answer,
+                outcome => panic!("unexpected outcome {:?}", outcome),
+            })
+            .collect();
+        assert_eq!(answers, ["220", "19208"]);
+        writer.join().unwrap()?;
+        Ok(())
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Runaway;
+
+    impl Solution for Runaway {
+        type Input = ();
+        type Answer1 = u8;
+        type Answer2 = u8;
+
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn default_input(&self) -> &'static str {
+            "day10/example.txt"
+        }
+
+        fn parse(&self, _: &Path) -> Result<Self::Input> {
+            Ok(())
+        }
+
+        fn generate(&self, _: &mut Rng, _: usize) -> Result<Generated> {
+            Ok(Generated::default())
+        }
+
+        fn part1(&self, _: &Self::Input) -> Result<Self::Answer1> {
+            loop {
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        fn part2(&self, _: &Self::Input) -> Result<Self::Answer2> {
+            let mut hoard = vec![];
+            loop {
+                hoard.push(std::hint::black_box(vec![0u8; 1 << 20]));
+            }
+        }
+    }
+
+    #[test]
+    fn enforce_limits() {
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(100)),
+            memory: Some(16 << 20),
+        };
+        let jobs: Vec<_> = Part::ALL
+            .iter()
+            .map(|&part| Job {
+                puzzle: &Runaway,
+                parts: vec![part],
+                path: PathBuf::from("day10/example.txt"),
+                explain: false,
+            })
+            .collect();
+        let finished = run(&jobs, 2, limits);
+        assert!(matches!(
+            finished[0].outcome,
+            Outcome::TimedOut(timeout) if timeout == Duration::from_millis(100)
+        ));
+        assert!(matches!(finished[1].outcome, Outcome::OutOfMemory(cap) if cap == 16 << 20));
+    }
+}
@@ -0,0 +1,35 @@
+//! The `.expected` sidecar of a puzzle example (i.e. of a `dayNN/example*.txt`), which holds the
+//! expected answers to the example.
+//!
+//! Each line holds `<part> <answer>`, with `<part>` being 1 or 2; blank lines and lines starting
+//! with `#` are ignored.
+//!
+//! This module is also compiled into `build.rs` (which generates a test per expected answer), so
+//! it must not depend on the rest of the crate.
+
+/// Parse a `line` of a sidecar into the part and the expected answer to it; `None` if the line is
+/// blank or a comment.
+pub fn parse_line(line: &str) -> Result<Option<(u8, String)>, String> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+    match line.split_whitespace().collect::<Vec<_>>()[..] {
+        [part @ ("1" | "2"), answer] => Ok(Some((part.parse().unwrap(), answer.to_owned()))),
+        _ => Err("expected `<part> <answer>` with <part> 1 or 2".to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lines() {
+        assert_eq!(parse_line("  # comment"), Ok(None));
+        assert_eq!(parse_line(""), Ok(None));
+        assert_eq!(parse_line("2 62 "), Ok(Some((2, "62".to_owned()))));
+        assert!(parse_line("3 62").is_err());
+        assert!(parse_line("1").is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::client::Outcome;
+use crate::{read_parsed_with, Malformed, Part, Result};
+
+/// An answer that has been submitted, along with the verdict on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl FromStr for Submission {
+    type Err = Malformed;
+
+    /// Parse an entry of the form `<day> <part> <answer> <outcome>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<_> = s.split_whitespace().collect();
+        let offset = |i: usize| fields[i].as_ptr() as usize - s.as_ptr() as usize;
+        let (day, part, answer, outcome) = match fields[..] {
+            [day, part, answer, outcome] => (day, part, answer, outcome),
+            _ => return Err(Malformed::new("expected `<day> <part> <answer> <outcome>`")),
+        };
+        Ok(Submission {
+            day: day
+                .parse()
+                .map_err(|err| Malformed::from(err).at(offset(0)))?,
+            part: part
+                .parse()
+                .map_err(|err: anyhow::Error| Malformed::new(err).at(offset(1)))?,
+            answer: answer.to_owned(),
+            outcome: outcome
+                .parse()
+                .map_err(|err: String| Malformed::new(err).at(offset(3)))?,
+        })
+    }
+}
+
+impl Submission {
+    /// Explain why the `answer` to `part` of `day` should not be submitted, given the `history`
+    /// of earlier submissions; i.e., if it has been submitted before, if the part has been solved
+    /// already, or if an earlier verdict proves it wrong.
+    pub fn refusal(history: &[Submission], day: u8, part: Part, answer: &str) -> Option<String> {
+        let earlier = history.iter().filter(|s| s.day == day && s.part == part);
+        let value = answer.parse::<i128>().ok();
+        for s in earlier {
+            let bound = s.answer.parse::<i128>().ok();
+            match (s.outcome, value, bound) {
+                _ if s.answer == answer => {
+                    return Some(format!(
+                        "{} has already been submitted ({})",
+                        answer, s.outcome
+                    ))
+                }
+                (Outcome::Correct, _, _) => {
+                    return Some(format!(
+                        "the part has already been solved with {}",
+                        s.answer
+                    ))
+                }
+                (Outcome::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                    return Some(format!("{} was already too high", s.answer))
+                }
+                (Outcome::TooLow, Some(value), Some(bound)) if value <= bound => {
+                    return Some(format!("{} was already too low", s.answer))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Read the submissions recorded in the given `Path`; a missing file means none have been made.
+pub fn read_submissions<P: AsRef<Path>>(path: P) -> Result<Vec<Submission>> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    Ok(read_parsed_with(path, |line| {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+        line.parse().map(Some)
+    })?
+    .into_iter()
+    .flatten()
+    .collect())
+}
+
+/// Append the given `submission` to the record in the given `Path`.
+pub fn record<P: AsRef<Path>>(path: P, submission: &Submission) -> Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(
+        file,
+        "{} {} {} {}",
+        submission.day, submission.part, submission.answer, submission.outcome
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn submission(s: &str) -> Submission {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_submission() {
+        assert_eq!(
+            submission("9 1 1234 too-low"),
+            Submission {
+                day: 9,
+                part: Part::One,
+                answer: "1234".to_owned(),
+                outcome: Outcome::TooLow,
+            }
+        );
+        assert_eq!("9 1 1234 meh".parse::<Submission>().unwrap_err().offset, 9);
+        assert!("9 1 1234".parse::<Submission>().is_err());
+    }
+
+    #[test]
+    fn refuse_submissions() {
+        let history = vec![
+            submission("9 1 100 too-high"),
+            submission("9 1 10 too-low"),
+            submission("9 1 abc incorrect"),
+            submission("9 2 42 correct"),
+        ];
+        let refused = |part, answer| Submission::refusal(&history, 9, part, answer).is_some();
+        assert!(refused(Part::One, "abc"));
+        assert!(refused(Part::One, "100"));
+        assert!(refused(Part::One, "150"));
+        assert!(refused(Part::One, "7"));
+        assert!(!refused(Part::One, "50"));
+        assert!(!refused(Part::One, "xyz"));
+        assert!(refused(Part::Two, "43"));
+        assert!(Submission::refusal(&history, 10, Part::One, "100").is_none());
+    }
+
+    #[test]
+    fn record_and_read() -> Result<()> {
+        let dir = TempDir::new("submissions");
+        let path = dir.join("submissions.txt");
+        assert!(read_submissions(&path)?.is_empty());
+        record(&path, &submission("1 2 5 too-high"))?;
+        record(&path, &submission("1 2 4 correct"))?;
+        assert_eq!(read_submissions(&path)?.len(), 2);
+        Ok(())
+    }
+}
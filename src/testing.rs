@@ -0,0 +1,29 @@
+//! Fixtures shared by the tests of several modules.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A directory of its own within the temporary one, which is removed along with its contents when
+/// dropped, i.e. even if the test that uses it fails.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Create a directory named after `name` (which sets it apart from those of other tests) and
+    /// the current process.
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+
+    /// The path of `name` within the directory.
+    pub fn join<P: AsRef<Path>>(&self, name: P) -> PathBuf {
+        self.0.join(name)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
@@ -0,0 +1,127 @@
+//! Watching the input and the examples of a day for changes (through inotify on Linux), so that the
+//! day can be solved again as soon as any of them is edited.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher as _};
+
+use crate::{is_example, Error, Result};
+
+/// How long the files must be left alone after a change before it is reported, so that the many
+/// events of a single save (e.g., truncating, writing and renaming) are reported at once.
+const QUIET_PERIOD: Duration = Duration::from_millis(100);
+
+/// A watch on the input of a day and on the examples (and their sidecars) in its directory.
+///
+/// The directories that contain them are watched, rather than the files themselves, so that files
+/// replaced on save (as many editors do) or added later are still noticed.
+pub struct Watcher {
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    input: PathBuf,
+    dir: PathBuf,
+}
+
+impl Watcher {
+    /// Watch the `input` of a day, along with the examples in its directory, `dir`.
+    pub fn new<P: AsRef<Path>, Q: AsRef<Path>>(input: P, dir: Q) -> Result<Self> {
+        let (tx, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx).map_err(watch_error)?;
+        // The events name the files through the paths that are watched, so both are canonical.
+        let dir = dir.as_ref().canonicalize()?;
+        let input = input.as_ref();
+        let input_dir = match input.parent() {
+            Some(parent) if parent != Path::new("") => parent.canonicalize()?,
+            _ => std::env::current_dir()?,
+        };
+        let input = input_dir.join(input.file_name().ok_or_else(|| {
+            Error::InvalidInput(format!("cannot watch {:?}, which is not a file", input))
+        })?);
+        for dir in [&dir, &input_dir] {
+            watcher
+                .watch(dir, RecursiveMode::NonRecursive)
+                .map_err(watch_error)?;
+        }
+        Ok(Watcher {
+            _watcher: watcher,
+            events,
+            input,
+            dir,
+        })
+    }
+
+    /// Block until the input or any of the examples is created, modified or removed, and return
+    /// the files that changed (in order of their names) once they are left alone.
+    pub fn wait(&self) -> Result<Vec<PathBuf>> {
+        let mut changed = vec![];
+        loop {
+            let event = if changed.is_empty() {
+                self.events.recv().map_err(|_| disconnected())?
+            } else {
+                match self.events.recv_timeout(QUIET_PERIOD) {
+                    Ok(event) => event,
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return Err(disconnected()),
+                }
+            };
+            let event = event.map_err(watch_error)?;
+            // Reading the files (e.g., to solve the day) must not count as changing them.
+            if !(event.kind.is_create() || event.kind.is_modify() || event.kind.is_remove()) {
+                continue;
+            }
+            changed.extend(event.paths.into_iter().filter(|path| self.is_watched(path)));
+        }
+        changed.sort();
+        changed.dedup();
+        Ok(changed)
+    }
+
+    /// Whether `path` is the input or one of the examples.
+    fn is_watched(&self, path: &Path) -> bool {
+        path == self.input || (path.parent() == Some(&self.dir) && is_example(path))
+    }
+}
+
+fn watch_error(err: notify::Error) -> Error {
+    match err.kind {
+        notify::ErrorKind::Io(err) => Error::Io(err),
+        _ => Error::InvalidInput(format!("cannot watch for changes: {}", err)),
+    }
+}
+
+fn disconnected() -> Error {
+    Error::InvalidInput("stopped watching for changes unexpectedly".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn report_changes() -> Result<()> {
+        let tmp = TempDir::new("watch");
+        let dir = tmp.join("day01");
+        fs::create_dir(&dir)?;
+        let watcher = Watcher::new(tmp.join("input.txt"), &dir)?;
+
+        fs::write(tmp.join("notes.txt"), "unrelated\n")?;
+        fs::write(dir.join("solution.rs"), "// unrelated\n")?;
+        fs::write(dir.join("example.txt"), "1721\n979\n")?;
+        fs::write(tmp.join("input.txt"), "1721\n")?;
+        let root = tmp.join("").canonicalize()?;
+        assert_eq!(
+            watcher.wait()?,
+            vec![root.join("day01/example.txt"), root.join("input.txt")]
+        );
+
+        fs::read_to_string(dir.join("example.txt"))?;
+        fs::write(dir.join("example.expected"), "1: 514579\n")?;
+        assert_eq!(watcher.wait()?, vec![root.join("day01/example.expected")]);
+        Ok(())
+    }
+}